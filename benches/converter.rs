@@ -0,0 +1,41 @@
+//! Compares a cached Converter against calling cast on every value.
+//! Run with `cargo bench --bench converter`.
+
+use scalar_types::{Converter, Endian};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const VALUES: u32 = 1_000_000;
+const ROUNDS: u32 = 20;
+
+fn time<F: FnMut() -> u64>(mut f: F) -> Duration {
+    // Warm up before timing
+    black_box(f());
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let values: Vec<Endian<u32>> = (0..VALUES).map(Endian::new).collect();
+    let order = Endian::Big(());
+    let converter = Converter::new(order);
+
+    let cast = time(|| {
+        black_box(&values).iter()
+            .map(|value| value.cast(black_box(order)).unwrap_or_default() as u64)
+            .sum()
+    });
+
+    let convert = time(|| {
+        black_box(&values).iter()
+            .map(|value| black_box(&converter).convert(*value) as u64)
+            .sum()
+    });
+
+    println!("cast:    {:?} per {} values", cast, VALUES);
+    println!("convert: {:?} per {} values", convert, VALUES);
+}
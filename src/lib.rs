@@ -21,7 +21,7 @@
 //! 
 //! ```
 //! use scalar_types::Endian;
-//! use std::io::{BufReader, Result};
+//! use std::io::{BufReader, Read, Result};
 //!  
 //! fn read_some_stuff() -> Result<()> {
 //!     // Binary file contains 01 | 00 00 00 02 .. ..
@@ -118,12 +118,58 @@
 //! }
 //! ```
 //! 
+//! Some formats mix endianness inside a single struct. A big endian header might embed
+//! one field that is always little endian. Since the endianness is just a variable,
+//! we can pass the struct's order to most fields, and override it for the odd one out.
+//! 
+//! ```
+//! use scalar_types::Endian;
+//! 
+//! struct Header {
+//!     magic: u32,
+//!     version: u16,
+//!     checksum: u32   // Always little endian, no matter the rest of the file
+//! }
+//! 
+//! fn read_header<StreamT: std::io::Read>(stream: &mut StreamT, order: Endian<()>) -> Option<Header> {
+//!     Some(Header {
+//!         magic: Endian::<u32>::from_stream(stream)?.cast(order)?,
+//!         version: Endian::<u16>::from_stream(stream)?.cast(order)?,
+//!         checksum: Endian::<u32>::from_stream(stream)?.cast(Endian::Little(()))?
+//!     })
+//! }
+//! 
+//! fn main() {
+//!     let data = [0u8, 0, 0, 1, 0, 2, 3, 0, 0, 0];
+//!     let header = read_header(&mut &data[..], Endian::Big(())).unwrap();
+//! 
+//!     assert_eq!(header.magic, 1);
+//!     assert_eq!(header.version, 2);
+//!     assert_eq!(header.checksum, 3);
+//! }
+//! ```
+//! 
 //! This was created to assist me with creating modding tools for video games. As some games share the same
 //! data, but the endianness changes based off the console it was built for. Working this way allows me to
 //! use the same code for all systems, and cast the values to the native endianness dynamically when needed.
 //! I figured I would share it on the off chance other people may find it useful.
 
 /// This error shouldn't really be possible, but out of abundance of caution it has been included.
+///
+/// The enum is marked #[non_exhaustive] so new variants can be added without breaking anyone.
+/// Matching on it outside of this crate requires a wildcard arm.
+/// ```
+/// use scalar_types::{get_native_endianness, Error};
+/// fn main() {
+///     if let Err(error) = get_native_endianness() {
+///         match error {
+///             Error::UnknownArchitecture => panic!("Unknown architecture!"),
+///             _ => panic!("Unable to determine the native endianness!")
+///         }
+///     }
+/// }
+/// ```
+#[non_exhaustive]
 pub enum Error {
     UnknownArchitecture
 }
@@ -146,18 +192,524 @@ pub fn get_native_endianness() -> Result<Endian<()>, Error> {
     }
 }
 
+/// Encodes `value` in the narrowest of 1, 2, 4, or 8 bytes that can hold it, in `order`.
+/// The encoding starts with a width byte holding the number of value bytes that follow (1, 2, 4, or 8).
+/// Endian::Native(()) writes the value bytes in the system's endianness.
+/// read_minimal_width reads this encoding back.
+/// ```
+/// use scalar_types::{minimal_width_bytes, Endian};
+/// fn main() {
+///     assert_eq!(minimal_width_bytes(0x12, Endian::Big(())), vec![1, 0x12]);
+///     assert_eq!(minimal_width_bytes(0x1234, Endian::Big(())), vec![2, 0x12, 0x34]);
+///     assert_eq!(minimal_width_bytes(0x123456, Endian::Little(())), vec![4, 0x56, 0x34, 0x12, 0]);
+/// }
+/// ```
+pub fn minimal_width_bytes(value: u64, order: Endian<()>) -> Vec<u8> {
+    let width = if value <= u8::MAX as u64 {
+        1
+    } else if value <= u16::MAX as u64 {
+        2
+    } else if value <= u32::MAX as u64 {
+        4
+    } else {
+        8
+    };
+
+    let big = match order {
+        Endian::Little(()) => false,
+        Endian::Big(()) => true,
+        Endian::Native(()) => native_endianness().is_big()
+    };
+
+    let mut bytes = vec![width as u8];
+    if big {
+        bytes.extend_from_slice(&value.to_be_bytes()[8 - width..]);
+    } else {
+        bytes.extend_from_slice(&value.to_le_bytes()[..width]);
+    }
+
+    bytes
+}
+
+/// Reads a value written by minimal_width_bytes: a width byte, followed by that many value bytes in `order`.
+/// Returns None on a short read, or if the width byte isn't 1, 2, 4, or 8.
+/// ```
+/// use scalar_types::{minimal_width_bytes, read_minimal_width, Endian};
+/// fn main() {
+///     let encoded = minimal_width_bytes(0x1234, Endian::Big(()));
+///
+///     assert_eq!(read_minimal_width(&mut &encoded[..], Endian::Big(())), Some(0x1234));
+/// }
+/// ```
+pub fn read_minimal_width<StreamT: std::io::Read>(stream: &mut StreamT, order: Endian<()>) -> Option<u64> {
+    let mut width = [0u8];
+    stream.read_exact(&mut width).ok()?;
+
+    let width = width[0] as usize;
+    if !matches!(width, 1 | 2 | 4 | 8) {
+        return None;
+    }
+
+    let big = match order {
+        Endian::Little(()) => false,
+        Endian::Big(()) => true,
+        Endian::Native(()) => native_endianness().is_big()
+    };
+
+    let mut bytes = [0u8; 8];
+    if big {
+        stream.read_exact(&mut bytes[8 - width..]).ok()?;
+        Some(u64::from_be_bytes(bytes))
+    } else {
+        stream.read_exact(&mut bytes[..width]).ok()?;
+        Some(u64::from_le_bytes(bytes))
+    }
+}
+
+/// Get's the systems native endianness, the same as get_native_endianness; but cached, and infallible.
+/// Detection only happens on the first call, so this is free to call in hot loops.
+/// If runtime detection ever did fail, the compile time target_endian is used instead.
+/// The result is always Endian::Little or Endian::Big, never Endian::Native.
+/// ```
+/// use scalar_types::{native_endianness, Endian};
+/// fn main() {
+///     let expected = if cfg!(target_endian = "big") { Endian::Big(()) } else { Endian::Little(()) };
+///
+///     assert_eq!(native_endianness(), expected);
+///     assert_eq!(native_endianness(), native_endianness());
+/// }
+/// ```
+pub fn native_endianness() -> Endian<()> {
+    static NATIVE: std::sync::OnceLock<Endian<()>> = std::sync::OnceLock::new();
+
+    *NATIVE.get_or_init(|| match get_native_endianness() {
+        Ok(order) => order,
+        Err(_) => if cfg!(target_endian = "big") { Endian::Big(()) } else { Endian::Little(()) }
+    })
+}
+
+/// HostInfo
+/// What the crate knows about the system it is running on. Returned by host_info.
+/// Handy to include in bug reports when a parse misbehaves on an unusual platform.
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+pub struct HostInfo {
+    /// The system's endianness, Endian::Little(()) or Endian::Big(())
+    pub endianness: Endian<()>,
+    /// The size of a pointer in bits
+    pub pointer_width: u32,
+    /// True if the endianness came from get_native_endianness at runtime.
+    /// False if runtime detection failed, and the compile time target_endian was used instead.
+    pub runtime_detection: bool
+}
+
+impl std::fmt::Display for HostInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let endianness = if self.endianness.is_big() { "big" } else { "little" };
+        let detection = if self.runtime_detection { "runtime" } else { "compile time" };
+
+        write!(f, "{} endian ({} detection), {} bit pointers", endianness, detection, self.pointer_width)
+    }
+}
+
+/// Returns what the crate knows about the system it is running on.
+/// ```
+/// use scalar_types::host_info;
+/// fn main() {
+///     let info = host_info();
+///
+///     assert_eq!(info.endianness.is_big(), cfg!(target_endian = "big"));
+///     assert_eq!(info.pointer_width as usize, std::mem::size_of::<usize>() * 8);
+///     println!("{}", info);
+/// }
+/// ```
+pub fn host_info() -> HostInfo {
+    let pointer_width = usize::BITS;
+
+    match get_native_endianness() {
+        Ok(endianness) => HostInfo { endianness, pointer_width, runtime_detection: true },
+        Err(_) => {
+            let endianness = if cfg!(target_endian = "big") { Endian::Big(()) } else { Endian::Little(()) };
+            HostInfo { endianness, pointer_width, runtime_detection: false }
+        }
+    }
+}
+
+/// Reads an unsigned LEB128 variable length integer from any type that implements the std::io::Read trait.
+/// LEB128 is used by formats like DWARF and WebAssembly. It is byte oriented, so it isn't endian sensitive.
+///
+/// Returns None on a short read, or if the encoding doesn't fit in a u64.
+/// ```
+/// use scalar_types::read_uleb128;
+/// fn main() {
+///     let data = [0xE5u8, 0x8E, 0x26];
+///
+///     assert_eq!(read_uleb128(&mut &data[..]), Some(624485));
+/// }
+/// ```
+pub fn read_uleb128<StreamT: std::io::Read>(stream: &mut StreamT) -> Option<u64> {
+    let mut result = 0u64;
+    let mut shift = 0;
+
+    loop {
+        let mut byte = [0u8];
+        stream.read_exact(&mut byte).ok()?;
+
+        let low = (byte[0] & 0x7F) as u64;
+        // Only one bit of the final group still fits in a u64
+        if shift == 63 && low > 1 {
+            return None;
+        }
+        result |= low << shift;
+
+        if byte[0] & 0x80 == 0 {
+            return Some(result);
+        }
+
+        shift += 7;
+        if shift >= 64 {
+            return None;
+        }
+    }
+}
+
+/// Reads a signed LEB128 variable length integer from any type that implements the std::io::Read trait.
+/// LEB128 is used by formats like DWARF and WebAssembly. It is byte oriented, so it isn't endian sensitive.
+///
+/// Returns None on a short read, or if the encoding doesn't fit in a i64.
+/// ```
+/// use scalar_types::read_sleb128;
+/// fn main() {
+///     let data = [0xC0u8, 0xBB, 0x78];
+///
+///     assert_eq!(read_sleb128(&mut &data[..]), Some(-123456));
+/// }
+/// ```
+pub fn read_sleb128<StreamT: std::io::Read>(stream: &mut StreamT) -> Option<i64> {
+    let mut result = 0i64;
+    let mut shift = 0;
+
+    loop {
+        let mut byte = [0u8];
+        stream.read_exact(&mut byte).ok()?;
+
+        let low = (byte[0] & 0x7F) as i64;
+        // Only the sign bit of the final group still fits in a i64, the rest must be sign extension
+        if shift == 63 && low != 0 && low != 0x7F {
+            return None;
+        }
+        result |= low << shift;
+        shift += 7;
+
+        if byte[0] & 0x80 == 0 {
+            // Sign extend from the last group read
+            if shift < 64 && byte[0] & 0x40 != 0 {
+                result |= -1i64 << shift;
+            }
+            return Some(result);
+        }
+
+        if shift >= 64 {
+            return None;
+        }
+    }
+}
+
+/// Reads a T from standard input, and casts it from `order` to native endianness.
+/// Stdin is locked for the duration of the read. Handy for quick command line filters.
+/// ```
+/// use scalar_types::{from_stdin, Endian};
+///
+/// fn read_header_from_pipe() -> Option<u32> {
+///     from_stdin::<u32>(Endian::Big(()))
+/// }
+/// ```
+pub fn from_stdin<T: Scalar>(order: Endian<()>) -> Option<T> {
+    let mut stdin = std::io::stdin().lock();
+    Endian::<T>::from_stream(&mut stdin)?.cast(order)
+}
+
+/// Returns an iterator that reads T values from standard input, casting each from `order` to native endianness.
+/// Stdin stays locked for as long as the iterator is alive. Iteration stops once a full value can no longer be read.
+/// ```
+/// use scalar_types::{stream_stdin, Endian};
+///
+/// fn sum_from_pipe() -> u64 {
+///     stream_stdin::<u32>(Endian::Little(())).map(u64::from).sum()
+/// }
+/// ```
+pub fn stream_stdin<T: Scalar>(order: Endian<()>) -> impl Iterator<Item = T> {
+    let mut stdin = std::io::stdin().lock();
+    std::iter::from_fn(move || Endian::<T>::from_stream(&mut stdin)?.cast(order))
+}
+
 /// Endian
 /// This wraps a scalar value and specializes the value for a specific endianness.
 /// In doing so it allows us to tag endian sensitive content, and safely pass it between functions
 /// for applications that may require this.
 #[derive(Debug)]
 #[derive(PartialEq)]
+#[derive(Clone, Copy)]
 pub enum Endian<T> {
     Little(T),
     Big(T),
     Native(T)
 }
 
+/// Order
+/// A value only version of Endian<()>, naming an endianness without the unit payloads.
+/// Reads a little cleaner than Endian::Big(()), and is easier to match on exhaustively.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Clone, Copy)]
+pub enum Order {
+    Little,
+    Big,
+    Native
+}
+
+impl From<Order> for Endian<()> {
+    fn from(order: Order) -> Endian<()> {
+        match order {
+            Order::Little => Endian::Little(()),
+            Order::Big => Endian::Big(()),
+            Order::Native => Endian::Native(())
+        }
+    }
+}
+
+impl From<Endian<()>> for Order {
+    fn from(order: Endian<()>) -> Order {
+        match order {
+            Endian::Little(()) => Order::Little,
+            Endian::Big(()) => Order::Big,
+            Endian::Native(()) => Order::Native
+        }
+    }
+}
+
+/// ScalarKind
+/// Names each of the scalar types at runtime.
+/// Useful for data driven parsers, where a schema decides the type of a field.
+///
+/// Marked #[non_exhaustive], since optional features (like "half") add kinds.
+/// Matching on it outside of this crate requires a wildcard arm.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub enum ScalarKind {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    F32,
+    F64,
+    /// Half precision float, only readable with the "half" feature
+    F16
+}
+
+/// Scalar
+/// Marks the primitive types Endian is designed to wrap.
+/// Every bit pattern is a valid value for these types, so they can be read straight from raw bytes.
+///
+/// # Safety
+/// Implementors must be plain data: every bit pattern has to be a valid value, and the type can't contain padding.
+/// The stream readers and byte views bounded on Scalar fill values straight from stream bytes, and expose their bytes, relying on this.
+/// Implementing it for a type like bool would make those functions undefined behaviour.
+/// The original Endian::from_stream is only bounded on Copy + Default, so it's up to the caller to only read plain data with it.
+pub unsafe trait Scalar: Copy + Default {
+    /// The size of the type in bytes
+    const SIZE: usize;
+
+    /// Returns the runtime name of the type
+    /// ```
+    /// use scalar_types::{Scalar, ScalarKind};
+    /// fn main() {
+    ///     assert_eq!(u32::kind(), ScalarKind::U32);
+    /// }
+    /// ```
+    fn kind() -> ScalarKind;
+}
+
+macro_rules! impl_scalar {
+    ($($scalar:ty => $kind:ident),*) => {
+        $(
+            unsafe impl Scalar for $scalar {
+                const SIZE: usize = std::mem::size_of::<$scalar>();
+
+                fn kind() -> ScalarKind {
+                    ScalarKind::$kind
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(
+    u8 => U8, u16 => U16, u32 => U32, u64 => U64, u128 => U128,
+    i8 => I8, i16 => I16, i32 => I32, i64 => I64, i128 => I128,
+    f32 => F32, f64 => F64
+);
+
+/// Half precision floats, as a 2 byte scalar. Only available with the "half" feature.
+/// ```
+/// use scalar_types::Endian;
+/// use half::f16;
+/// fn main() {
+///     // 1.5 as a big endian half float
+///     let data = [0x3Eu8, 0x00];
+///     let value = Endian::<f16>::from_stream(&mut &data[..]);
+///
+///     assert_eq!(value.and_then(|value| value.cast(Endian::Big(()))), Some(f16::from_f32(1.5)));
+/// }
+/// ```
+#[cfg(feature = "half")]
+unsafe impl Scalar for half::f16 {
+    const SIZE: usize = 2;
+
+    fn kind() -> ScalarKind {
+        ScalarKind::F16
+    }
+}
+
+/// ScalarValue
+/// Holds a native endian value of any of the scalar types, for when the type is only known at runtime.
+/// Returned by read_dyn.
+///
+/// Marked #[non_exhaustive], since optional features (like "half") add variants.
+/// Matching on it outside of this crate requires a wildcard arm.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub enum ScalarValue {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    F32(f32),
+    F64(f64),
+    #[cfg(feature = "half")]
+    F16(half::f16)
+}
+
+impl ScalarValue {
+    /// Returns the kind of scalar held.
+    pub fn kind(&self) -> ScalarKind {
+        match self {
+            ScalarValue::U8(_) => ScalarKind::U8,
+            ScalarValue::U16(_) => ScalarKind::U16,
+            ScalarValue::U32(_) => ScalarKind::U32,
+            ScalarValue::U64(_) => ScalarKind::U64,
+            ScalarValue::U128(_) => ScalarKind::U128,
+            ScalarValue::I8(_) => ScalarKind::I8,
+            ScalarValue::I16(_) => ScalarKind::I16,
+            ScalarValue::I32(_) => ScalarKind::I32,
+            ScalarValue::I64(_) => ScalarKind::I64,
+            ScalarValue::I128(_) => ScalarKind::I128,
+            ScalarValue::F32(_) => ScalarKind::F32,
+            ScalarValue::F64(_) => ScalarKind::F64,
+            #[cfg(feature = "half")]
+            ScalarValue::F16(_) => ScalarKind::F16
+        }
+    }
+}
+
+/// Reads the next value from the stream, with its type chosen at runtime by `kind`.
+/// The value is cast from `order` to native endianness. For fully data driven binary inspection.
+///
+/// Returns None on a short read.
+/// ScalarKind::F16 also returns None, unless the "half" feature is enabled.
+/// ```
+/// use scalar_types::{read_dyn, Endian, ScalarKind, ScalarValue};
+/// fn main() {
+///     let data = [0u8, 7, 0, 0, 0, 9];
+///     let mut stream = &data[..];
+///
+///     let schema = [ScalarKind::U16, ScalarKind::I32];
+///     let values: Vec<ScalarValue> = schema.iter()
+///         .filter_map(|kind| read_dyn(&mut stream, *kind, Endian::Big(())))
+///         .collect();
+///
+///     assert_eq!(values, vec![ScalarValue::U16(7), ScalarValue::I32(9)]);
+///     assert_eq!(values[1].kind(), ScalarKind::I32);
+/// }
+/// ```
+pub fn read_dyn<StreamT: std::io::Read>(stream: &mut StreamT, kind: ScalarKind, order: Endian<()>) -> Option<ScalarValue> {
+    let value = match kind {
+        ScalarKind::U8 => ScalarValue::U8(Endian::<u8>::from_stream(stream)?.cast(order)?),
+        ScalarKind::U16 => ScalarValue::U16(Endian::<u16>::from_stream(stream)?.cast(order)?),
+        ScalarKind::U32 => ScalarValue::U32(Endian::<u32>::from_stream(stream)?.cast(order)?),
+        ScalarKind::U64 => ScalarValue::U64(Endian::<u64>::from_stream(stream)?.cast(order)?),
+        ScalarKind::U128 => ScalarValue::U128(Endian::<u128>::from_stream(stream)?.cast(order)?),
+        ScalarKind::I8 => ScalarValue::I8(Endian::<i8>::from_stream(stream)?.cast(order)?),
+        ScalarKind::I16 => ScalarValue::I16(Endian::<i16>::from_stream(stream)?.cast(order)?),
+        ScalarKind::I32 => ScalarValue::I32(Endian::<i32>::from_stream(stream)?.cast(order)?),
+        ScalarKind::I64 => ScalarValue::I64(Endian::<i64>::from_stream(stream)?.cast(order)?),
+        ScalarKind::I128 => ScalarValue::I128(Endian::<i128>::from_stream(stream)?.cast(order)?),
+        ScalarKind::F32 => ScalarValue::F32(Endian::<f32>::from_stream(stream)?.cast(order)?),
+        ScalarKind::F64 => ScalarValue::F64(Endian::<f64>::from_stream(stream)?.cast(order)?),
+        #[cfg(feature = "half")]
+        ScalarKind::F16 => ScalarValue::F16(Endian::<half::f16>::from_stream(stream)?.cast(order)?),
+        #[cfg(not(feature = "half"))]
+        ScalarKind::F16 => return None
+    };
+
+    Some(value)
+}
+
+/// FromStrRadix
+/// Parses a value from a string in a given radix.
+/// Implemented for the integer scalar types, by forwarding to their from_str_radix.
+/// ```
+/// use scalar_types::FromStrRadix;
+/// fn main() {
+///     assert_eq!(<u8 as FromStrRadix>::from_str_radix("ff", 16), Some(255));
+///     assert_eq!(<u8 as FromStrRadix>::from_str_radix("1", 1), None);
+/// }
+/// ```
+pub trait FromStrRadix: Sized {
+    /// Returns None if `source` isn't a valid number in `radix`, or doesn't fit in the type.
+    /// Also returns None if `radix` isn't in 2..=36, rather than panicking.
+    fn from_str_radix(source: &str, radix: u32) -> Option<Self>;
+}
+
+macro_rules! impl_from_str_radix {
+    ($($scalar:ty),*) => {
+        $(
+            impl FromStrRadix for $scalar {
+                fn from_str_radix(source: &str, radix: u32) -> Option<$scalar> {
+                    // The inherent from_str_radix panics outside of this range
+                    if !(2..=36).contains(&radix) {
+                        return None;
+                    }
+
+                    <$scalar>::from_str_radix(source, radix).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str_radix!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// Upper bound on how many elements are allocated up front when the element count comes from the stream.
+/// Larger counts still work, the vector just grows as values are actually read.
+const MAX_PREALLOCATION: usize = 1024;
+
 /// UNSAFE
 /// Swap the endianness of a value by casting the value's memory
 /// to a slice and reversing the slice.
@@ -173,8 +725,68 @@ fn endian_swap_unsafe<DataT>(mut value: DataT) -> DataT {
     value
 }
 
+/// UNSAFE
+/// View the value's memory as a slice of bytes.
+/// Bounded on Scalar, so there is never any uninitialised padding to expose.
+/// 
+/// Marked unsafe as it uses a raw pointer; however, 
+/// the unsafe code is bounded by the size of the variable
+/// and should never reach unowned memory.
+fn bytes_of_unsafe<DataT: Scalar>(value: &DataT) -> &[u8] {
+    let ptr: *const DataT = value;
+    unsafe { std::slice::from_raw_parts(ptr as *const u8, std::mem::size_of_val(value)) }
+}
+
+/// UNSAFE
+/// View the value's memory as a mutable slice of bytes, so it can be filled straight from a stream.
+/// Whatever is written must be a valid bit pattern for DataT; new callers should be bounded on Scalar.
+/// 
+/// Marked unsafe as it uses a raw pointer; however, 
+/// the unsafe code is bounded by the size of the variable
+/// and should never reach unowned memory.
+fn bytes_of_mut_unsafe<DataT: Copy>(value: &mut DataT) -> &mut [u8] {
+    let ptr: *mut DataT = value;
+    unsafe { std::slice::from_raw_parts_mut(ptr as *mut u8, std::mem::size_of::<DataT>()) }
+}
+
 
 impl<T: Copy + Default> Endian<T> {
+    /// Tags a value with the given endianness. No byte manipulation occurs;
+    /// the caller asserts the value is already stored in that endianness.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::with_order(0x1234u16, Endian::Big(()));
+    ///
+    ///     assert_eq!(scalar_types, Endian::Big(0x1234u16));
+    /// }
+    /// ```
+    pub fn with_order(value: T, order: Endian<()>) -> Endian<T> {
+        match order {
+            Endian::Little(()) => Endian::Little(value),
+            Endian::Big(()) => Endian::Big(value),
+            Endian::Native(()) => Endian::Native(value)
+        }
+    }
+
+    /// Returns the raw stored value, ignoring the endianness tag.
+    fn raw(&self) -> T {
+        match self {
+            Endian::Little(value) => *value,
+            Endian::Big(value) => *value,
+            Endian::Native(value) => *value
+        }
+    }
+
+    /// Returns the endianness tag, without the value.
+    fn order(&self) -> Endian<()> {
+        match self {
+            Endian::Little(_) => Endian::Little(()),
+            Endian::Big(_) => Endian::Big(()),
+            Endian::Native(_) => Endian::Native(())
+        }
+    }
+
     /// All values are read in as "Endian::Native(T)". It can be converted between to the desired endianness when needed.
     /// ```
     /// use scalar_types::Endian;
@@ -186,6 +798,48 @@ impl<T: Copy + Default> Endian<T> {
         Endian::Native(value)
     }
 
+    /// Const constructor for a Endian::Big(T).
+    /// Along with little and native, these can build tables of Endian constants.
+    /// ```
+    /// use scalar_types::Endian;
+    ///
+    /// const MAGIC: [Endian<u32>; 2] = [Endian::big(0x1234), Endian::little(0x3412)];
+    /// static DEFAULT: Endian<u16> = Endian::native(42);
+    ///
+    /// fn main() {
+    ///     assert_eq!(MAGIC[0].is_big(), true);
+    ///     assert_eq!(MAGIC[1].is_little(), true);
+    ///     assert_eq!(DEFAULT.is_native(), true);
+    /// }
+    /// ```
+    pub const fn big(value: T) -> Endian<T> {
+        Endian::Big(value)
+    }
+
+    /// Const constructor for a Endian::Little(T).
+    pub const fn little(value: T) -> Endian<T> {
+        Endian::Little(value)
+    }
+
+    /// Const constructor for a Endian::Native(T).
+    pub const fn native(value: T) -> Endian<T> {
+        Endian::Native(value)
+    }
+
+    /// Returns the number of bytes that must be available before a T can be read.
+    /// Useful for framing code that needs to request exactly enough data from its transport.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     const NEEDED: usize = Endian::<u32>::required_bytes();
+    ///
+    ///     assert_eq!(NEEDED, 4);
+    /// }
+    /// ```
+    pub const fn required_bytes() -> usize {
+        std::mem::size_of::<T>()
+    }
+
     /// UNSAFE
     /// 
     /// Reads and returns a Endian::Native(T) from any type that implements the std:io::Read trait. 
@@ -210,10 +864,53 @@ impl<T: Copy + Default> Endian<T> {
     ///     Ok(())
     ///  }
     /// ```
+    ///
+    /// Different scalar types can be read back to back from the same stream.
+    /// Each read advances the stream by exactly the size of its own type, so a record mixing every
+    /// supported type reads back correctly, and the stream ends up exactly at the end of the record.
+    /// ```
+    /// use scalar_types::Endian;
+    /// use std::io::Cursor;
+    /// fn main() {
+    ///     // A big endian record holding one of every scalar type
+    ///     let mut record = Vec::new();
+    ///     record.extend_from_slice(&0x12u8.to_be_bytes());
+    ///     record.extend_from_slice(&0x1234u16.to_be_bytes());
+    ///     record.extend_from_slice(&0x12345678u32.to_be_bytes());
+    ///     record.extend_from_slice(&0x123456789ABCDEF0u64.to_be_bytes());
+    ///     record.extend_from_slice(&0x123456789ABCDEF0123456789ABCDEF0u128.to_be_bytes());
+    ///     record.extend_from_slice(&(-0x12i8).to_be_bytes());
+    ///     record.extend_from_slice(&(-0x1234i16).to_be_bytes());
+    ///     record.extend_from_slice(&(-0x12345678i32).to_be_bytes());
+    ///     record.extend_from_slice(&(-0x123456789ABCDEF0i64).to_be_bytes());
+    ///     record.extend_from_slice(&(-0x123456789ABCDEF0123456789ABCDEF0i128).to_be_bytes());
+    ///     record.extend_from_slice(&1.5f32.to_be_bytes());
+    ///     record.extend_from_slice(&(-2.25f64).to_be_bytes());
+    ///
+    ///     let order = Endian::Big(());
+    ///     let mut stream = Cursor::new(record);
+    ///
+    ///     assert_eq!(Endian::<u8>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(0x12));
+    ///     assert_eq!(Endian::<u16>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(0x1234));
+    ///     assert_eq!(Endian::<u32>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(0x12345678));
+    ///     assert_eq!(Endian::<u64>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(0x123456789ABCDEF0));
+    ///     assert_eq!(Endian::<u128>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(0x123456789ABCDEF0123456789ABCDEF0));
+    ///     assert_eq!(Endian::<i8>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(-0x12));
+    ///     assert_eq!(Endian::<i16>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(-0x1234));
+    ///     assert_eq!(Endian::<i32>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(-0x12345678));
+    ///     assert_eq!(Endian::<i64>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(-0x123456789ABCDEF0));
+    ///     assert_eq!(Endian::<i128>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(-0x123456789ABCDEF0123456789ABCDEF0));
+    ///     assert_eq!(Endian::<f32>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(1.5));
+    ///     assert_eq!(Endian::<f64>::from_stream(&mut stream).and_then(|value| value.cast(order)), Some(-2.25));
+    ///
+    ///     // 1 + 2 + 4 + 8 + 16 bytes, twice, then 4 + 8
+    ///     assert_eq!(stream.position(), 74);
+    ///     assert_eq!(Endian::<u8>::from_stream(&mut stream), None);
+    /// }
+    /// ```
     pub fn from_stream<StreamT: std::io::Read>(stream: &mut StreamT) -> Option<Endian<T>> {
         let mut value = T::default();
-        let ptr: *mut T = &mut value;
-        let buffer = unsafe { std::slice::from_raw_parts_mut(ptr as *mut u8, std::mem::size_of_val(&value)) };
+        let buffer = bytes_of_mut_unsafe(&mut value);
 
         match stream.read_exact(buffer) {
             Err(_) => None,
@@ -221,145 +918,344 @@ impl<T: Copy + Default> Endian<T> {
         }
     }
 
-    /// Attempts to cast the value held by Endian to a big endian value.
-    /// Only fail condition is if get_native_endianness fails somehow
+    /// Reads a T from the stream like from_stream, but tags it as Endian::Big(T) instead of Endian::Native(T).
+    /// No swap happens at read time; the bytes are stored exactly as read, and only swapped
+    /// when the value is later cast (e.g. with as_native). This records the known source endianness with the value.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let data = [0u8, 2];
+    ///     let value = Endian::<u16>::from_stream_as_be(&mut &data[..]);
     ///
-    /// This shouldn't really be possible; however, it does call unsafe. 
-    /// so, out of abundance of caution we include the fail condition.
+    ///     assert_eq!(value.and_then(|value| value.as_native()), Some(2));
+    /// }
+    /// ```
+    pub fn from_stream_as_be<StreamT: std::io::Read>(stream: &mut StreamT) -> Option<Endian<T>>
+    where T: Scalar {
+        Endian::<T>::from_stream(stream).map(|value| value.relabel(Endian::Big(())))
+    }
+
+    /// Reads a T from the stream like from_stream, but tags it as Endian::Little(T) instead of Endian::Native(T).
+    /// No swap happens at read time; the bytes are stored exactly as read, and only swapped
+    /// when the value is later cast (e.g. with as_native). This records the known source endianness with the value.
     /// ```
     /// use scalar_types::Endian;
     /// fn main() {
-    ///     let scalar_types = Endian::new(42u16);
-    /// 
-    ///     let be_scalar_types = match scalar_types.as_big() {
-    ///         Some(value) => value,
-    ///         None => panic!("Unable to convert endianness!")
-    ///     };
+    ///     let data = [2u8, 0];
+    ///     let value = Endian::<u16>::from_stream_as_le(&mut &data[..]);
+    ///
+    ///     assert_eq!(value.and_then(|value| value.as_native()), Some(2));
     /// }
     /// ```
-    pub fn as_big(&self) -> Option<T> {
-        match self {
-            Endian::Little(value) => Some(endian_swap_unsafe(*value)),
-            Endian::Big(value) => Some(*value),
-            Endian::Native(value) => {
-                match get_native_endianness() {
-                    Err(_) => None,
-                    Ok(order) => match order {
-                        Endian::Little(()) => Some(endian_swap_unsafe(*value)),
-                        Endian::Big(()) => Some(*value),
-
-                        // Native Endianness being "Native" infinitely recursive
-                        Endian::Native(()) => None 
-                    }
-                }
-            }
-        }
+    pub fn from_stream_as_le<StreamT: std::io::Read>(stream: &mut StreamT) -> Option<Endian<T>>
+    where T: Scalar {
+        Endian::<T>::from_stream(stream).map(|value| value.relabel(Endian::Little(())))
     }
 
-    /// Attempts to cast the value held by Endian to a little endian value.
-    /// Only fail condition is if get_native_endianness fails somehow
+    /// Returns an iterator that repeatedly reads Endian::Native(T) values from the stream.
+    /// Each value is yielded alongside the byte offset it started at, counted from
+    /// the position of the stream when iteration began.
     ///
-    /// This shouldn't really be possible; however, it does call unsafe. 
-    /// so, out of abundance of caution we include the fail condition.
+    /// Iteration stops once a full value can no longer be read (EOF, or a trailing partial value).
     /// ```
     /// use scalar_types::Endian;
     /// fn main() {
-    ///     let scalar_types = Endian::new(42u16);
-    /// 
-    ///     let le_scalar_types = match scalar_types.as_little() {
-    ///         Some(value) => value,
-    ///         None => panic!("Unable to convert endianness!")
-    ///     };
+    ///     let data = [1u8, 0, 2, 0, 3, 0, 4];
+    ///     let mut stream = &data[..];
+    ///
+    ///     let offsets: Vec<u64> = Endian::<u16>::stream_iter_with_offset(&mut stream)
+    ///         .map(|(offset, _)| offset)
+    ///         .collect();
+    ///
+    ///     // The trailing byte isn't enough for another u16
+    ///     assert_eq!(offsets, vec![0, 2, 4]);
     /// }
     /// ```
-    pub fn as_little(&self) -> Option<T>  {
-        match self {
-            Endian::Little(value) => Some(*value),
-            Endian::Big(value) => Some(endian_swap_unsafe(*value)),
-            Endian::Native(value) => {
-                match get_native_endianness() {
-                    Err(_) => None,
-                    Ok(order) => match order {
-                        Endian::Little(()) => Some(*value),
-                        Endian::Big(()) => Some(endian_swap_unsafe(*value)),
-                        
-                        // Native Endianness being "Native" infinitely recursive
-                        Endian::Native(()) => None 
-                    }
-                }
-            }
-        }
+    pub fn stream_iter_with_offset<'a, StreamT: std::io::Read>(stream: &'a mut StreamT) -> impl Iterator<Item = (u64, Endian<T>)> + 'a
+    where T: Scalar + 'a {
+        let mut offset = 0u64;
+        std::iter::from_fn(move || {
+            let value = Endian::<T>::from_stream(stream)?;
+            let start = offset;
+            offset += Self::required_bytes() as u64;
+
+            Some((start, value))
+        })
     }
 
-    /// Attempts to cast the value held by Endian to a native endian value.
-    /// Only fail condition is if get_native_endianness fails somehow
+    /// Returns an iterator that repeatedly reads Endian::Native(T) values from the stream, yielding io::Result items.
+    /// Unlike from_stream, this tells a clean end of stream apart from a real error:
+    /// - EOF before the first byte of a value ends the iteration
+    /// - EOF part way through a value yields an UnexpectedEof error
+    /// - Any other I/O error is yielded as is
     ///
-    /// This shouldn't really be possible; however, it does call unsafe. 
-    /// so, out of abundance of caution we include the fail condition.
+    /// The iterator ends after yielding an error.
     /// ```
     /// use scalar_types::Endian;
     /// fn main() {
-    ///     let scalar_types = Endian::new(42u16);
-    /// 
-    ///     let le_scalar_types = match scalar_types.as_native() {
-    ///         Some(value) => value,
-    ///         None => panic!("Unable to convert endianness!")
-    ///     };
+    ///     let data = [1u8, 0, 2, 0];
+    ///     let values: std::io::Result<Vec<Endian<u16>>> = Endian::try_stream_iter(&mut &data[..]).collect();
+    ///     assert_eq!(values.unwrap().len(), 2);
+    ///
+    ///     // The trailing byte is only half a u16
+    ///     let data = [1u8, 0, 2];
+    ///     let values: std::io::Result<Vec<Endian<u16>>> = Endian::try_stream_iter(&mut &data[..]).collect();
+    ///     assert_eq!(values.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
     /// }
     /// ```
-    pub fn as_native(&self) -> Option<T>  {
-        match self {
-            Endian::Little(value) => match get_native_endianness() {
-                Err(_) => None,
-                Ok(order) => match order {
-                    Endian::Little(()) => Some(*value),
-                    Endian::Big(()) => Some(endian_swap_unsafe(*value)),
-                    
-                    // Native Endianness being "Native" infinitely recursive
-                    Endian::Native(()) => None 
-                }
+    pub fn try_stream_iter<'a, StreamT: std::io::Read>(stream: &'a mut StreamT) -> impl Iterator<Item = std::io::Result<Endian<T>>> + 'a
+    where T: Scalar + 'a {
+        let mut finished = false;
+        std::iter::from_fn(move || {
+            if finished {
+                return None;
             }
-            
-            Endian::Big(value) => {
-                match get_native_endianness() {
-                    Err(_) => None,
-                    Ok(order) => match order {
-                        Endian::Little(()) => Some(endian_swap_unsafe(*value)),
-                        Endian::Big(()) => Some(*value),
-                        
-                        // Native Endianness being "Native" infinitely recursive
-                        Endian::Native(()) => None 
+
+            let mut value = T::default();
+            let buffer = bytes_of_mut_unsafe(&mut value);
+
+            let mut filled = 0;
+            while filled < buffer.len() {
+                match stream.read(&mut buffer[filled..]) {
+                    Ok(0) => break,
+                    Ok(read) => filled += read,
+                    Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(error) => {
+                        finished = true;
+                        return Some(Err(error));
                     }
                 }
             }
 
-            Endian::Native(value) => Some(*value),
-        }
+            if filled == 0 {
+                finished = true;
+                None
+            } else if filled < buffer.len() {
+                finished = true;
+                Some(Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "Stream ended part way through a value")))
+            } else {
+                Some(Ok(Endian::Native(value)))
+            }
+        })
     }
 
-    /// Attempts to cast the value held by Endian to a specified endianness
-    /// Only fail condition is if get_native_endianness fails somehow
+    /// Reads a count of type LengthT, followed by that many T values.
+    /// The count is cast from `order` to native, and each element is tagged with `order`.
     ///
-    /// This shouldn't really be possible; however, it does call unsafe. 
-    /// so, out of abundance of caution we include the fail condition.
+    /// Returns None on any short read, or if the count doesn't fit in a usize.
+    /// The count comes from the stream, so it isn't trusted for allocation;
+    /// the vector grows as values are actually read instead.
     /// ```
     /// use scalar_types::Endian;
     /// fn main() {
-    ///     let scalar_types = Endian::new(42u16);
-    /// 
-    ///     let le_scalar_types = scalar_types.cast(Endian::Little(()));
-    ///     let be_scalar_types = scalar_types.cast(Endian::Big(()));
-    ///     let ne_scalar_types = scalar_types.cast(Endian::Native(()));
+    ///     // Big endian u16 count of 2, followed by two big endian u32 values
+    ///     let data = [0u8, 2, 0, 0, 0, 7, 0, 0, 0, 9];
+    ///     let mut stream = &data[..];
+    ///
+    ///     let values = match Endian::<u32>::read_length_prefixed_array::<u16, _>(&mut stream, Endian::Big(())) {
+    ///         Some(values) => values,
+    ///         None => panic!("Unable to parse array from stream!")
+    ///     };
+    ///
+    ///     assert_eq!(values.len(), 2);
+    ///     assert_eq!(values[0].as_native(), Some(7));
+    ///     assert_eq!(values[1].as_native(), Some(9));
     /// }
     /// ```
-    pub fn cast(&self, order: Endian<()>) -> Option<T> {
-        match order {
-            Endian::Little(()) => self.as_little(),
-            Endian::Big(()) => self.as_big(),
-            Endian::Native(()) => self.as_native()
+    pub fn read_length_prefixed_array<LengthT: Scalar, StreamT: std::io::Read>(stream: &mut StreamT, order: Endian<()>) -> Option<Vec<Endian<T>>>
+    where T: Scalar, usize: TryFrom<LengthT> {
+        let length = Endian::<LengthT>::from_stream(stream)?.cast(order)?;
+        let count = usize::try_from(length).ok()?;
+
+        // A count this large can never be read, so there is no reason to try
+        count.checked_mul(Self::required_bytes())?;
+
+        let mut values = Vec::with_capacity(count.min(MAX_PREALLOCATION));
+        for _ in 0..count {
+            let value = Endian::<T>::from_stream(stream)?;
+            values.push(Endian::with_order(value.raw(), order));
+        }
+
+        Some(values)
+    }
+
+    /// Casts every value in the slice to `order`, and writes them to any type that implements the std::io::Write trait.
+    /// The values are gathered into a single buffer first, so the stream only sees one write_all.
+    ///
+    /// Returns the first I/O error encountered.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let values = [Endian::new(1u16), Endian::new(2u16)];
+    ///     let mut output = Vec::new();
+    ///
+    ///     if Endian::slice_to_stream(&values, &mut output, Endian::Big(())).is_err() {
+    ///         panic!("Unable to write values to stream!");
+    ///     }
+    ///
+    ///     assert_eq!(output, vec![0, 1, 0, 2]);
+    /// }
+    /// ```
+    pub fn slice_to_stream<StreamT: std::io::Write>(values: &[Endian<T>], stream: &mut StreamT, order: Endian<()>) -> std::io::Result<()>
+    where T: Scalar {
+        let mut buffer = Vec::with_capacity(values.len() * Self::required_bytes());
+        for value in values {
+            match value.cast(order) {
+                Some(cast) => buffer.extend_from_slice(bytes_of_unsafe(&cast)),
+                None => return Err(std::io::Error::other("Unable to determine the native endianness"))
+            }
+        }
+
+        stream.write_all(&buffer)
+    }
+
+    /// Collects plain values into a vector of Endian::Native(T) values.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let values = Endian::collect_endian(vec![1u32, 2, 3]);
+    ///
+    ///     assert_eq!(values, vec![Endian::Native(1u32), Endian::Native(2), Endian::Native(3)]);
+    /// }
+    /// ```
+    pub fn collect_endian<IterT: IntoIterator<Item = T>>(values: IterT) -> Vec<Endian<T>> {
+        values.into_iter().map(Endian::from).collect()
+    }
+
+    /// Casts every value in the slice to native endianness, and collects them into a vector.
+    /// If any of the casts fail, None is returned instead.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let values = vec![Endian::new(1u32), Endian::new(2u32)];
+    ///
+    ///     assert_eq!(Endian::collect_native(&values), Some(vec![1u32, 2]));
+    /// }
+    /// ```
+    pub fn collect_native(values: &[Endian<T>]) -> Option<Vec<T>> {
+        values.iter().map(Endian::as_native).collect()
+    }
+
+    /// Attempts to cast the value held by Endian to a big endian value.
+    /// The native endianness is always known, so this always returns Some.
+    /// The Option is kept so existing callers don't break.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::new(42u16);
+    /// 
+    ///     let be_scalar_types = match scalar_types.as_big() {
+    ///         Some(value) => value,
+    ///         None => panic!("Unable to convert endianness!")
+    ///     };
+    /// }
+    /// ```
+    pub fn as_big(&self) -> Option<T> {
+        match self {
+            Endian::Little(value) => Some(endian_swap_unsafe(*value)),
+            Endian::Big(value) => Some(*value),
+            Endian::Native(value) => if native_endianness().is_little() {
+                Some(endian_swap_unsafe(*value))
+            } else {
+                Some(*value)
+            }
+        }
+    }
+
+    /// Attempts to cast the value held by Endian to a little endian value.
+    /// The native endianness is always known, so this always returns Some.
+    /// The Option is kept so existing callers don't break.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::new(42u16);
+    /// 
+    ///     let le_scalar_types = match scalar_types.as_little() {
+    ///         Some(value) => value,
+    ///         None => panic!("Unable to convert endianness!")
+    ///     };
+    /// }
+    /// ```
+    pub fn as_little(&self) -> Option<T>  {
+        match self {
+            Endian::Little(value) => Some(*value),
+            Endian::Big(value) => Some(endian_swap_unsafe(*value)),
+            Endian::Native(value) => if native_endianness().is_little() {
+                Some(*value)
+            } else {
+                Some(endian_swap_unsafe(*value))
+            }
+        }
+    }
+
+    /// Attempts to cast the value held by Endian to a native endian value.
+    /// The native endianness is always known, so this always returns Some.
+    /// The Option is kept so existing callers don't break.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::new(42u16);
+    /// 
+    ///     let le_scalar_types = match scalar_types.as_native() {
+    ///         Some(value) => value,
+    ///         None => panic!("Unable to convert endianness!")
+    ///     };
+    /// }
+    /// ```
+    pub fn as_native(&self) -> Option<T>  {
+        match self {
+            Endian::Little(value) => if native_endianness().is_little() {
+                Some(*value)
+            } else {
+                Some(endian_swap_unsafe(*value))
+            }
+
+            Endian::Big(value) => if native_endianness().is_little() {
+                Some(endian_swap_unsafe(*value))
+            } else {
+                Some(*value)
+            }
+
+            Endian::Native(value) => Some(*value),
+        }
+    }
+
+    /// Attempts to cast the value held by Endian to a specified endianness
+    /// The native endianness is always known, so this always returns Some.
+    /// The Option is kept so existing callers don't break.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::new(42u16);
+    /// 
+    ///     let le_scalar_types = scalar_types.cast(Endian::Little(()));
+    ///     let be_scalar_types = scalar_types.cast(Endian::Big(()));
+    ///     let ne_scalar_types = scalar_types.cast(Endian::Native(()));
+    /// }
+    /// ```
+    pub fn cast(&self, order: Endian<()>) -> Option<T> {
+        match order {
+            Endian::Little(()) => self.as_little(),
+            Endian::Big(()) => self.as_big(),
+            Endian::Native(()) => self.as_native()
         }
     }
 
+    /// Attempts to cast the value held by Endian to a specified endianness.
+    /// The same as cast, but takes the value only Order enum instead of Endian<()>.
+    /// ```
+    /// use scalar_types::{Endian, Order};
+    /// fn main() {
+    ///     let scalar_types = Endian::Little(0x1234u16);
+    ///
+    ///     assert_eq!(scalar_types.cast_order(Order::Big), Some(0x3412u16));
+    ///     assert_eq!(scalar_types.cast_order(Order::Little), Some(0x1234u16));
+    /// }
+    /// ```
+    pub fn cast_order(&self, order: Order) -> Option<T> {
+        self.cast(order.into())
+    }
+
     // Returns true if Endian is a Endian::Little option
     /// ```
     /// use scalar_types::Endian;
@@ -414,6 +1310,28 @@ impl<T: Copy + Default> Endian<T> {
         }
     }
 
+    /// Returns a reference to the stored value, but only if it is tagged with `order`.
+    /// No conversion happens; this only inspects the tag, so the reference is to the bytes exactly as stored.
+    ///
+    /// Handy for skipping a swap when a value is already in the endianness we need.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::Big(42u16);
+    ///
+    ///     assert_eq!(scalar_types.as_tagged(Endian::Big(())), Some(&42u16));
+    ///     assert_eq!(scalar_types.as_tagged(Endian::Little(())), None);
+    /// }
+    /// ```
+    pub fn as_tagged(&self, order: Endian<()>) -> Option<&T> {
+        match (self, order) {
+            (Endian::Little(value), Endian::Little(())) => Some(value),
+            (Endian::Big(value), Endian::Big(())) => Some(value),
+            (Endian::Native(value), Endian::Native(())) => Some(value),
+            _ => None
+        }
+    }
+
     /// Unpack the value as a native endian value.
     /// If casting fails, the default value for the type is returned instead
     /// Not recommended for production.
@@ -427,7 +1345,7 @@ impl<T: Copy + Default> Endian<T> {
     /// }
     /// ```
     /// Output: 
-    /// ```
+    /// ```text
     ///     "the meaning of life the universe and everything: 42"
     /// ```
     pub fn unpack(&self) -> T {    
@@ -436,4 +1354,1587 @@ impl<T: Copy + Default> Endian<T> {
         }
         T::default()
     }
+
+    /// Attempts to narrow the value held by Endian to a smaller type U.
+    /// The value is cast to native endianness first, then converted with U::try_from.
+    ///
+    /// This operates on the logical value, not the bytes. So an Endian<u32> holding 42
+    /// narrows to 42u16 no matter which endianness it is tagged with.
+    /// If the value doesn't fit in U (overflow), None is returned.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let small_count = Endian::new(42u32);
+    ///     let large_count = Endian::new(70000u32);
+    ///
+    ///     assert_eq!(small_count.to_narrower::<u16>(), Some(42u16));
+    ///     assert_eq!(large_count.to_narrower::<u16>(), None);
+    /// }
+    /// ```
+    pub fn to_narrower<U: TryFrom<T>>(&self) -> Option<U> {
+        match self.as_native() {
+            Some(value) => U::try_from(value).ok(),
+            None => None
+        }
+    }
+
+    /// Reads a value from the stream, and casts it from `order` to native endianness.
+    /// Then compares it against `null_sentinel`, for formats that use a special value to mean "no value".
+    ///
+    /// The result is a nested Option:
+    /// - None: the value couldn't be read (or cast)
+    /// - Some(None): the value was read, and it was the sentinel
+    /// - Some(Some(value)): the value was read, and it wasn't the sentinel
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let data = [0xFFu8, 0xFF, 0xFF, 0xFF, 0, 0, 0, 8];
+    ///     let mut stream = &data[..];
+    ///
+    ///     let first = Endian::<u32>::read_optional(&mut stream, Endian::Big(()), 0xFFFFFFFF);
+    ///     let second = Endian::<u32>::read_optional(&mut stream, Endian::Big(()), 0xFFFFFFFF);
+    ///     let third = Endian::<u32>::read_optional(&mut stream, Endian::Big(()), 0xFFFFFFFF);
+    ///
+    ///     assert_eq!(first, Some(None));
+    ///     assert_eq!(second, Some(Some(8)));
+    ///     assert_eq!(third, None);
+    /// }
+    /// ```
+    pub fn read_optional<StreamT: std::io::Read>(stream: &mut StreamT, order: Endian<()>, null_sentinel: T) -> Option<Option<T>>
+    where T: Scalar + PartialEq {
+        let value = Endian::<T>::from_stream(stream)?.cast(order)?;
+
+        if value == null_sentinel {
+            Some(None)
+        } else {
+            Some(Some(value))
+        }
+    }
+
+    /// Reads a DISK byte wide field from the stream, and truncates it down to a T.
+    /// For formats that store a value in more bytes than we need (e.g. 8 bytes on disk, when we only want a u32).
+    ///
+    /// The field is interpreted in `order`, and the low order bytes are kept:
+    /// - Endian::Little(()): the first size_of::<T>() bytes of the field
+    /// - Endian::Big(()): the last size_of::<T>() bytes of the field
+    /// - Endian::Native(()): whichever of the two matches the system's endianness
+    ///
+    /// The result is tagged with `order`. Returns None on a short read, or if DISK is smaller than T.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     // Big endian u64 holding 0x0102
+    ///     let data = [0u8, 0, 0, 0, 0, 0, 1, 2];
+    ///
+    ///     let value = Endian::<u32>::from_stream_truncating::<8, _>(&mut &data[..], Endian::Big(()));
+    ///     assert_eq!(value.and_then(|value| value.as_native()), Some(0x0102));
+    ///
+    ///     let too_narrow = Endian::<u32>::from_stream_truncating::<2, _>(&mut &data[..], Endian::Big(()));
+    ///     assert_eq!(too_narrow, None);
+    /// }
+    /// ```
+    pub fn from_stream_truncating<const DISK: usize, StreamT: std::io::Read>(stream: &mut StreamT, order: Endian<()>) -> Option<Endian<T>>
+    where T: Scalar {
+        let size = Self::required_bytes();
+        if DISK < size {
+            return None;
+        }
+
+        let mut buffer = [0u8; DISK];
+        stream.read_exact(&mut buffer).ok()?;
+
+        let low_order_first = match order {
+            Endian::Little(()) => true,
+            Endian::Big(()) => false,
+            Endian::Native(()) => native_endianness().is_little()
+        };
+
+        let kept = if low_order_first {
+            &buffer[..size]
+        } else {
+            &buffer[DISK - size..]
+        };
+
+        let value = Endian::<T>::from_stream(&mut &kept[..])?;
+        Some(Endian::with_order(value.raw(), order))
+    }
+
+    /// WARNING
+    /// Changes only the endianness tag, the stored bytes are left untouched.
+    ///
+    /// This does NOT convert the value. The stored bytes are reinterpreted in the new endianness,
+    /// so the logical value changes whenever the old and new orders differ.
+    /// This is an escape hatch for transcoding pipelines that handle the byte swapping themselves.
+    /// To convert the value use cast, as_big, as_little, or as_native instead.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::Big(0x1234u16);
+    ///     let relabeled = scalar_types.relabel(Endian::Little(()));
+    ///
+    ///     // Same bytes, different tag
+    ///     assert_eq!(relabeled, Endian::Little(0x1234u16));
+    /// }
+    /// ```
+    pub fn relabel(self, order: Endian<()>) -> Endian<T> {
+        Endian::with_order(self.raw(), order)
+    }
+
+    /// Diagnostic aid, not intended for hot paths.
+    /// Writes `value` in `order`, reads the bytes back, and checks the result matches the original.
+    /// This exercises the full encode and decode path for a single value.
+    ///
+    /// Returns false if any step fails. Note that NaN floats never compare equal, so they return false as well.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     assert_eq!(Endian::verify_roundtrip(0x12345678u32, Endian::Big(())), true);
+    ///     assert_eq!(Endian::verify_roundtrip(-2.5f64, Endian::Little(())), true);
+    /// }
+    /// ```
+    pub fn verify_roundtrip(value: T, order: Endian<()>) -> bool
+    where T: Scalar + PartialEq {
+        let encoded = match Endian::new(value).cast(order) {
+            Some(encoded) => encoded,
+            None => return false
+        };
+
+        let mut bytes = bytes_of_unsafe(&encoded);
+        match Endian::<T>::from_stream(&mut bytes) {
+            Some(decoded) => decoded.cast(order) == Some(value),
+            None => false
+        }
+    }
+
+    /// Advances the stream to the next multiple of `alignment`, then reads a T and casts it from `order` to native endianness.
+    /// For formats where fields are padded out to an alignment (often their own size).
+    ///
+    /// The alignment is relative to the start of the stream (position 0), not to where the current record began.
+    /// Returns None if `alignment` is 0, or if the seek or read fails.
+    /// ```
+    /// use scalar_types::Endian;
+    /// use std::io::Cursor;
+    /// fn main() {
+    ///     // A u8, three bytes of padding, then a big endian u32
+    ///     let mut stream = Cursor::new(vec![7u8, 0, 0, 0, 0, 0, 0, 9]);
+    ///
+    ///     let first = Endian::<u8>::from_stream_aligned(&mut stream, 1, Endian::Big(()));
+    ///     let second = Endian::<u32>::from_stream_aligned(&mut stream, 4, Endian::Big(()));
+    ///
+    ///     assert_eq!(first, Some(7));
+    ///     assert_eq!(second, Some(9));
+    /// }
+    /// ```
+    pub fn from_stream_aligned<StreamT: std::io::Read + std::io::Seek>(stream: &mut StreamT, alignment: u64, order: Endian<()>) -> Option<T>
+    where T: Scalar {
+        if alignment == 0 {
+            return None;
+        }
+
+        let position = stream.stream_position().ok()?;
+        let padding = (alignment - position % alignment) % alignment;
+        stream.seek(std::io::SeekFrom::Current(i64::try_from(padding).ok()?)).ok()?;
+
+        Endian::<T>::from_stream(stream)?.cast(order)
+    }
+
+    /// Reads a 1 byte presence flag, then reads and casts a T from `order` to native endianness if the flag says one follows.
+    /// The flag byte is 0 when the value is absent, and anything else when it is present.
+    /// When absent, nothing past the flag byte is read.
+    ///
+    /// The result is a nested Option:
+    /// - None: the flag or value couldn't be read (or cast)
+    /// - Some(None): the flag was 0, so there is no value
+    /// - Some(Some(value)): the flag was nonzero, and the value was read
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let data = [1u8, 0, 5, 0];
+    ///     let mut stream = &data[..];
+    ///
+    ///     let first = Endian::<u16>::read_flagged_optional(&mut stream, Endian::Big(()));
+    ///     let second = Endian::<u16>::read_flagged_optional(&mut stream, Endian::Big(()));
+    ///
+    ///     assert_eq!(first, Some(Some(5)));
+    ///     assert_eq!(second, Some(None));
+    /// }
+    /// ```
+    pub fn read_flagged_optional<StreamT: std::io::Read>(stream: &mut StreamT, order: Endian<()>) -> Option<Option<T>>
+    where T: Scalar {
+        let mut flag = [0u8];
+        stream.read_exact(&mut flag).ok()?;
+
+        if flag[0] == 0 {
+            return Some(None);
+        }
+
+        Endian::<T>::from_stream(stream)?.cast(order).map(Some)
+    }
+
+    /// Formats a slice of values as a hexdump, one value per row, for checking parsed tables against a hex editor.
+    /// Each row holds the value's index, its byte offset, its bytes cast to `order`, and its native value.
+    ///
+    /// Values that fail to cast show "??" in place of their bytes or value.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let values = [Endian::new(0x1234u16), Endian::new(0xABCDu16)];
+    ///     let dump = Endian::hexdump(&values, Endian::Big(()));
+    ///
+    ///     assert_eq!(dump, "   0  00000000  12 34  4660\n   1  00000002  ab cd  43981\n");
+    /// }
+    /// ```
+    pub fn hexdump(values: &[Endian<T>], order: Endian<()>) -> String
+    where T: Scalar + std::fmt::Display {
+        let mut dump = String::new();
+        for (index, value) in values.iter().enumerate() {
+            let bytes = match value.cast(order) {
+                Some(cast) => bytes_of_unsafe(&cast)
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                None => String::from("??")
+            };
+
+            let native = match value.as_native() {
+                Some(native) => native.to_string(),
+                None => String::from("??")
+            };
+
+            let offset = index * Self::required_bytes();
+            dump.push_str(&format!("{:>4}  {:08x}  {}  {}\n", index, offset, bytes, native));
+        }
+
+        dump
+    }
+
+    /// Reads the next whitespace delimited token from a text stream, and parses it as a T in the given radix.
+    /// For "binary" formats that are actually text dumps of numbers. Text isn't endian sensitive,
+    /// so the value is returned as Endian::Native(T).
+    ///
+    /// Leading whitespace is skipped, and the whitespace ending the token is consumed.
+    /// Returns None at EOF, if the token doesn't parse, or if `radix` isn't in 2..=36.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let mut stream = "ff 10\n  7f".as_bytes();
+    ///
+    ///     assert_eq!(Endian::<u8>::read_text_scalar(&mut stream, 16), Some(Endian::Native(0xFF)));
+    ///     assert_eq!(Endian::<u8>::read_text_scalar(&mut stream, 16), Some(Endian::Native(0x10)));
+    ///     assert_eq!(Endian::<i8>::read_text_scalar(&mut stream, 16), Some(Endian::Native(0x7F)));
+    ///     assert_eq!(Endian::<u8>::read_text_scalar(&mut stream, 16), None);
+    /// }
+    /// ```
+    pub fn read_text_scalar<StreamT: std::io::BufRead>(stream: &mut StreamT, radix: u32) -> Option<Endian<T>>
+    where T: FromStrRadix {
+        let mut token = Vec::new();
+        loop {
+            let buffer = stream.fill_buf().ok()?;
+            if buffer.is_empty() {
+                break;
+            }
+
+            let mut used = 0;
+            let mut finished = false;
+            for &byte in buffer {
+                used += 1;
+                if !byte.is_ascii_whitespace() {
+                    token.push(byte);
+                } else if !token.is_empty() {
+                    finished = true;
+                    break;
+                }
+            }
+
+            stream.consume(used);
+            if finished {
+                break;
+            }
+        }
+
+        let text = std::str::from_utf8(&token).ok()?;
+        T::from_str_radix(text, radix).map(Endian::Native)
+    }
+
+    /// Returns the endianness every value in the slice is tagged with, or None if the tags differ.
+    /// A cheap check before handing a slice to a homogeneous serialization path.
+    ///
+    /// An empty slice has no common endianness, so it returns None.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let same = [Endian::Big(1u16), Endian::Big(2u16)];
+    ///     let mixed = [Endian::Big(1u16), Endian::Little(2u16)];
+    ///
+    ///     assert_eq!(Endian::all_same_order(&same), Some(Endian::Big(())));
+    ///     assert_eq!(Endian::all_same_order(&mixed), None);
+    ///     assert_eq!(Endian::<u16>::all_same_order(&[]), None);
+    /// }
+    /// ```
+    pub fn all_same_order(values: &[Endian<T>]) -> Option<Endian<()>> {
+        let order = values.first()?.order();
+
+        if values.iter().all(|value| value.order() == order) {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// Changes the endianness tag using a function of the current tag, swapping the bytes to match.
+    /// Unlike relabel, the logical value is kept; only the way it is stored changes.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::Big(0x1234u16);
+    ///
+    ///     // Flip between big and little
+    ///     let flipped = scalar_types.map_order(|order| match order {
+    ///         Endian::Big(()) => Endian::Little(()),
+    ///         Endian::Little(()) => Endian::Big(()),
+    ///         Endian::Native(()) => Endian::Native(())
+    ///     });
+    ///
+    ///     assert_eq!(flipped, Endian::Little(0x3412u16));
+    ///     assert_eq!(flipped.as_native(), scalar_types.as_native());
+    /// }
+    /// ```
+    pub fn map_order<MapT: FnOnce(Endian<()>) -> Endian<()>>(self, f: MapT) -> Endian<T> {
+        let order = f(self.order());
+        let value = self.cast(order).expect("casting never fails");
+        Endian::with_order(value, order)
+    }
+
+    /// Returns whichever of the two wrappers holds the larger value, keeping its endianness tag.
+    /// The comparison is on the native values, so differently tagged values compare correctly.
+    /// Like std::cmp::max, `other` is returned when they are equal.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let first = Endian::Big(0x0100u16);    // 1 on a little endian system
+    ///     let second = Endian::Little(0x0002u16);
+    ///
+    ///     assert_eq!(first.max_by_value(second), Endian::Little(0x0002u16));
+    /// }
+    /// ```
+    pub fn max_by_value(self, other: Endian<T>) -> Endian<T>
+    where T: Ord {
+        if other.as_native() >= self.as_native() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns whichever of the two wrappers holds the smaller value, keeping its endianness tag.
+    /// The comparison is on the native values, so differently tagged values compare correctly.
+    /// Like std::cmp::min, `self` is returned when they are equal.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let first = Endian::Big(0x0100u16);    // 1 on a little endian system
+    ///     let second = Endian::Little(0x0002u16);
+    ///
+    ///     assert_eq!(first.min_by_value(second), Endian::Big(0x0100u16));
+    /// }
+    /// ```
+    pub fn min_by_value(self, other: Endian<T>) -> Endian<T>
+    where T: Ord {
+        if other.as_native() < self.as_native() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Checks whether the stored value equals `host_value`, given the stored bytes are big endian.
+    /// The stored bytes are always interpreted as big endian, whatever the tag says (as if read straight from a big endian stream).
+    /// `host_value` is a plain number, written normally in the system's endianness.
+    ///
+    /// Handy for magic numbers, without having to remember which side needs casting.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let data = [0x7Fu8, b'E', b'L', b'F'];
+    ///     let magic = Endian::<u32>::from_stream(&mut &data[..]).unwrap();
+    ///
+    ///     assert_eq!(magic.equals_be(0x7F454C46), true);
+    ///     assert_eq!(magic.equals_le(0x7F454C46), false);
+    /// }
+    /// ```
+    pub fn equals_be(&self, host_value: T) -> bool
+    where T: PartialEq {
+        self.relabel(Endian::Big(())).as_native() == Some(host_value)
+    }
+
+    /// Checks whether the stored value equals `host_value`, given the stored bytes are little endian.
+    /// The stored bytes are always interpreted as little endian, whatever the tag says (as if read straight from a little endian stream).
+    /// `host_value` is a plain number, written normally in the system's endianness.
+    ///
+    /// Handy for magic numbers, without having to remember which side needs casting.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let data = [b'R', b'I', b'F', b'F'];
+    ///     let magic = Endian::<u32>::from_stream(&mut &data[..]).unwrap();
+    ///
+    ///     assert_eq!(magic.equals_le(0x46464952), true);
+    ///     assert_eq!(magic.equals_be(0x46464952), false);
+    /// }
+    /// ```
+    pub fn equals_le(&self, host_value: T) -> bool
+    where T: PartialEq {
+        self.relabel(Endian::Little(())).as_native() == Some(host_value)
+    }
+
+    /// Reads a T from a wider fixed size field, and casts it from `order` to native endianness.
+    /// For fields declared as e.g. "4 bytes on disk" that only hold a u16.
+    ///
+    /// The value is assumed to come first, with the padding after it. The padding
+    /// (field_bytes - size_of::<T>() bytes) is read and discarded, leaving the stream at the end of the field.
+    /// Returns None if `field_bytes` is smaller than T, or on a short read of either the value or the padding.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     // A big endian u16 in a 4 byte field, followed by a u8
+    ///     let data = [0u8, 5, 0xFF, 0xFF, 9];
+    ///     let mut stream = &data[..];
+    ///
+    ///     assert_eq!(Endian::<u16>::read_padded(&mut stream, 4, Endian::Big(())), Some(5));
+    ///     assert_eq!(Endian::<u8>::read_padded(&mut stream, 1, Endian::Big(())), Some(9));
+    /// }
+    /// ```
+    pub fn read_padded<StreamT: std::io::Read>(stream: &mut StreamT, field_bytes: usize, order: Endian<()>) -> Option<T>
+    where T: Scalar {
+        let padding = field_bytes.checked_sub(Self::required_bytes())? as u64;
+
+        let value = Endian::<T>::from_stream(stream)?.cast(order)?;
+
+        let skipped = std::io::copy(&mut std::io::Read::take(&mut *stream, padding), &mut std::io::sink()).ok()?;
+        if skipped != padding {
+            return None;
+        }
+
+        Some(value)
+    }
+
+    /// Escape hatch for reverse engineering. Returns the value as native endianness,
+    /// but interpreting the stored bytes in the opposite endianness to the tag.
+    /// So a Endian::Big is read as little endian, a Endian::Little as big endian,
+    /// and a Endian::Native as the opposite of the system's endianness.
+    ///
+    /// This is deliberately the "wrong" interpretation, for formats that store values byte swapped
+    /// relative to a marker. For the correct interpretation use as_native, as_big, or as_little.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let scalar_types = Endian::Big(0x1234u16);
+    ///
+    ///     assert_eq!(scalar_types.as_opposite(), scalar_types.relabel(Endian::Little(())).as_native());
+    /// }
+    /// ```
+    pub fn as_opposite(&self) -> Option<T> {
+        self.as_native().map(endian_swap_unsafe)
+    }
+
+    /// Reads a T from the stream, and returns it cast from `order` to native endianness,
+    /// along with a copy of the raw bytes exactly as they were read.
+    /// For annotated hex views that show each field's value next to its bytes.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     let data = [0x12u8, 0x34];
+    ///
+    ///     let field = Endian::<u16>::read_with_bytes(&mut &data[..], Endian::Big(()));
+    ///     assert_eq!(field, Some((0x1234, vec![0x12, 0x34])));
+    /// }
+    /// ```
+    pub fn read_with_bytes<StreamT: std::io::Read>(stream: &mut StreamT, order: Endian<()>) -> Option<(T, Vec<u8>)>
+    where T: Scalar {
+        let value = Endian::<T>::from_stream(stream)?;
+        let bytes = bytes_of_unsafe(&value.raw()).to_vec();
+
+        Some((value.cast(order)?, bytes))
+    }
+
+    /// Reads `count` elements, where each element is preceded by its own endianness flag byte.
+    /// A flag equal to `little` tags the element as Endian::Little, and one equal to `big` tags it as Endian::Big.
+    /// The bytes are stored as read; each element carries its own order, and is swapped when it is cast.
+    ///
+    /// Returns None on any short read, or if a flag matches neither `little` nor `big`.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     // A little endian 1, then a big endian 2
+    ///     let data = [0u8, 1, 0, 1, 0, 2];
+    ///
+    ///     let values = Endian::<u16>::read_per_element_flagged_array(&mut &data[..], 2, 0, 1).unwrap();
+    ///
+    ///     assert_eq!(values[0].is_little(), true);
+    ///     assert_eq!(values[1].is_big(), true);
+    ///     assert_eq!(Endian::collect_native(&values), Some(vec![1, 2]));
+    /// }
+    /// ```
+    pub fn read_per_element_flagged_array<StreamT: std::io::Read>(stream: &mut StreamT, count: usize, little: u8, big: u8) -> Option<Vec<Endian<T>>>
+    where T: Scalar {
+        let mut values = Vec::with_capacity(count.min(MAX_PREALLOCATION));
+        for _ in 0..count {
+            let mut flag = [0u8];
+            stream.read_exact(&mut flag).ok()?;
+
+            let order = if flag[0] == little {
+                Endian::Little(())
+            } else if flag[0] == big {
+                Endian::Big(())
+            } else {
+                return None;
+            };
+
+            let value = Endian::<T>::from_stream(stream)?;
+            values.push(value.relabel(order));
+        }
+
+        Some(values)
+    }
+
+    /// Casts the value to `order`, and returns a std::io::Cursor over its bytes.
+    /// For passing a single value to something that expects a stream.
+    /// Casts always succeed, so this always returns Some; the Option mirrors cast.
+    /// ```
+    /// use scalar_types::Endian;
+    /// use std::io::Read;
+    /// fn main() {
+    ///     let scalar_types = Endian::new(0x1234u16);
+    ///
+    ///     let mut cursor = scalar_types.byte_cursor(Endian::Big(())).unwrap();
+    ///     let mut bytes = Vec::new();
+    ///     cursor.read_to_end(&mut bytes).unwrap();
+    ///
+    ///     assert_eq!(bytes, vec![0x12, 0x34]);
+    /// }
+    /// ```
+    pub fn byte_cursor(&self, order: Endian<()>) -> Option<std::io::Cursor<Vec<u8>>>
+    where T: Scalar {
+        let cast = self.cast(order)?;
+        Some(std::io::Cursor::new(bytes_of_unsafe(&cast).to_vec()))
+    }
+
+    /// Checks whether `bytes`, interpreted in `order`, decode to `expected`.
+    /// A concise assertion that "these bytes mean this number in this endianness", for test fixtures and validation tools.
+    ///
+    /// Only the first size_of::<T>() bytes are read. Returns false if the slice is shorter than that.
+    /// ```
+    /// use scalar_types::Endian;
+    /// fn main() {
+    ///     assert_eq!(Endian::decodes_to(&[0x12, 0x34], Endian::Big(()), 0x1234u16), true);
+    ///     assert_eq!(Endian::decodes_to(&[0x12, 0x34], Endian::Little(()), 0x1234u16), false);
+    ///     assert_eq!(Endian::decodes_to(&[0x12], Endian::Big(()), 0x1234u16), false);
+    /// }
+    /// ```
+    pub fn decodes_to(bytes: &[u8], order: Endian<()>, expected: T) -> bool
+    where T: Scalar + PartialEq {
+        let mut stream = bytes;
+        match Endian::<T>::from_stream(&mut stream) {
+            Some(value) => value.cast(order) == Some(expected),
+            None => false
+        }
+    }
+}
+
+impl<T: Scalar> Endian<T> {
+    /// Reads a T from the stream and tags it with `order`, but only if T matches the `expected` kind.
+    /// Returns None if the kinds don't match, or if the read fails.
+    ///
+    /// This catches mismatches between a runtime schema and the type the code actually reads.
+    /// Nothing is read from the stream when the kinds don't match.
+    /// ```
+    /// use scalar_types::{Endian, ScalarKind};
+    /// fn main() {
+    ///     let data = [0u8, 0, 0, 2];
+    ///
+    ///     let value = Endian::<u32>::read_checked(&mut &data[..], ScalarKind::U32, Endian::Big(()));
+    ///     assert_eq!(value, Some(Endian::Big(u32::from_ne_bytes([0, 0, 0, 2]))));
+    ///
+    ///     let mismatch = Endian::<u16>::read_checked(&mut &data[..], ScalarKind::U32, Endian::Big(()));
+    ///     assert_eq!(mismatch, None);
+    /// }
+    /// ```
+    pub fn read_checked<StreamT: std::io::Read>(stream: &mut StreamT, expected: ScalarKind, order: Endian<()>) -> Option<Endian<T>> {
+        if T::kind() != expected {
+            return None;
+        }
+
+        let value = Endian::<T>::from_stream(stream)?;
+        Some(Endian::with_order(value.raw(), order))
+    }
+}
+
+impl Endian<u32> {
+    /// Interprets the value as an IPv4 address.
+    /// The value is cast using `order`, and the most significant byte of the result becomes the first octet.
+    ///
+    /// Network addresses are conventionally big endian, so a value read from a packet
+    /// should usually be interpreted with Endian::Big(()).
+    /// ```
+    /// use scalar_types::Endian;
+    /// use std::net::Ipv4Addr;
+    /// fn main() {
+    ///     // 192.168.0.1 as it appears on the wire
+    ///     let data = [192u8, 168, 0, 1];
+    ///     let value = Endian::<u32>::from_stream(&mut &data[..]).unwrap();
+    ///
+    ///     assert_eq!(value.as_ipv4(Endian::Big(())), Some(Ipv4Addr::new(192, 168, 0, 1)));
+    /// }
+    /// ```
+    pub fn as_ipv4(&self, order: Endian<()>) -> Option<std::net::Ipv4Addr> {
+        self.cast(order).map(std::net::Ipv4Addr::from)
+    }
+}
+
+impl Endian<u128> {
+    /// Interprets the value as an IPv6 address.
+    /// The value is cast using `order`, and the most significant byte of the result becomes the first octet.
+    ///
+    /// Network addresses are conventionally big endian, so a value read from a packet
+    /// should usually be interpreted with Endian::Big(()).
+    /// ```
+    /// use scalar_types::Endian;
+    /// use std::net::Ipv6Addr;
+    /// fn main() {
+    ///     // ::1 as it appears on the wire
+    ///     let mut data = [0u8; 16];
+    ///     data[15] = 1;
+    ///     let value = Endian::<u128>::from_stream(&mut &data[..]).unwrap();
+    ///
+    ///     assert_eq!(value.as_ipv6(Endian::Big(())), Some(Ipv6Addr::LOCALHOST));
+    /// }
+    /// ```
+    pub fn as_ipv6(&self, order: Endian<()>) -> Option<std::net::Ipv6Addr> {
+        self.cast(order).map(std::net::Ipv6Addr::from)
+    }
+}
+
+/// Middle endian (PDP-11) support for 32 bit values.
+/// PDP-11 stores a 32 bit value as two little endian 16 bit halves, most significant half first.
+/// So 0x0A0B0C0D is stored as the bytes 0B 0A 0D 0C.
+///
+/// This isn't an Endian variant; Endian only tags big, little, and native values.
+/// These helpers convert to and from the middle endian layout explicitly instead.
+macro_rules! impl_pdp {
+    ($($scalar:ty),*) => {
+        $(
+            impl Endian<$scalar> {
+                /// Swaps the bytes within each 16 bit half of the value, leaving the halves in place.
+                /// ```
+                /// use scalar_types::Endian;
+                /// fn main() {
+                #[doc = concat!("    assert_eq!(Endian::<", stringify!($scalar), ">::swap_pdp(0x0A0B0C0D), 0x0B0A0D0C);")]
+                /// }
+                /// ```
+                pub fn swap_pdp(value: $scalar) -> $scalar {
+                    let value = value as u32;
+                    (((value & 0x00FF00FF) << 8) | ((value >> 8) & 0x00FF00FF)) as $scalar
+                }
+
+                /// Decodes a value from its middle endian (PDP-11) bytes.
+                /// ```
+                /// use scalar_types::Endian;
+                /// fn main() {
+                #[doc = concat!("    assert_eq!(Endian::<", stringify!($scalar), ">::from_pdp_bytes([0x0B, 0x0A, 0x0D, 0x0C]), 0x0A0B0C0D);")]
+                /// }
+                /// ```
+                pub fn from_pdp_bytes(bytes: [u8; 4]) -> $scalar {
+                    Self::swap_pdp(<$scalar>::from_be_bytes(bytes))
+                }
+
+                /// Attempts to cast the value held by Endian to a middle endian (PDP-11) value.
+                /// Works like as_big and as_little; the result's bytes in memory are in middle endian order.
+                /// Since the conversion is its own inverse, this also decodes a raw value read from a middle endian stream.
+                /// ```
+                /// use scalar_types::Endian;
+                /// fn main() {
+                ///     let data = [0x0Bu8, 0x0A, 0x0D, 0x0C];
+                #[doc = concat!("    let raw = Endian::<", stringify!($scalar), ">::from_stream(&mut &data[..]).unwrap();")]
+                ///
+                ///     assert_eq!(raw.as_pdp(), Some(0x0A0B0C0D));
+                #[doc = concat!("    assert_eq!(Endian::new(0x0A0B0C0D", stringify!($scalar), ").as_pdp().map(|value| value.to_ne_bytes()), Some(data));")]
+                /// }
+                /// ```
+                pub fn as_pdp(&self) -> Option<$scalar> {
+                    self.as_native().map(|value| <$scalar>::from_ne_bytes(Self::swap_pdp(value).to_be_bytes()))
+                }
+            }
+        )*
+    };
+}
+
+impl_pdp!(u32, i32);
+
+/// Normalization for integer samples, like color channels or audio.
+/// Unsigned values map to 0.0..=1.0, and signed values map to -1.0..=1.0.
+macro_rules! impl_normalized {
+    (unsigned: $($scalar:ty),*) => {
+        $(
+            impl Endian<$scalar> {
+                /// Casts the value using `order`, then normalizes it to 0.0..=1.0.
+                #[doc = concat!("The divisor is ", stringify!($scalar), "::MAX, so 0 maps to 0.0 and ", stringify!($scalar), "::MAX maps to 1.0.")]
+                /// ```
+                /// use scalar_types::Endian;
+                /// fn main() {
+                #[doc = concat!("    let scalar_types = Endian::new(", stringify!($scalar), "::MAX);")]
+                ///
+                ///     assert_eq!(scalar_types.as_normalized(Endian::Native(())), Some(1.0));
+                /// }
+                /// ```
+                pub fn as_normalized(&self, order: Endian<()>) -> Option<f64> {
+                    self.cast(order).map(|value| value as f64 / <$scalar>::MAX as f64)
+                }
+            }
+        )*
+    };
+    (signed: $($scalar:ty),*) => {
+        $(
+            impl Endian<$scalar> {
+                /// Casts the value using `order`, then normalizes it to -1.0..=1.0.
+                #[doc = concat!("The divisor is ", stringify!($scalar), "::MAX, so ", stringify!($scalar), "::MAX maps to 1.0.")]
+                #[doc = concat!(stringify!($scalar), "::MIN is one step further from zero than ", stringify!($scalar), "::MAX, so it is clamped to -1.0.")]
+                /// ```
+                /// use scalar_types::Endian;
+                /// fn main() {
+                #[doc = concat!("    let scalar_types = Endian::new(", stringify!($scalar), "::MIN);")]
+                ///
+                ///     assert_eq!(scalar_types.as_normalized(Endian::Native(())), Some(-1.0));
+                /// }
+                /// ```
+                pub fn as_normalized(&self, order: Endian<()>) -> Option<f64> {
+                    self.cast(order).map(|value| (value as f64 / <$scalar>::MAX as f64).max(-1.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_normalized!(unsigned: u8, u16, u32, u64);
+impl_normalized!(signed: i8, i16, i32, i64);
+
+/// Bit reversal for the integer types, for bit packed formats that store bits in the opposite order.
+/// For example 0b0000_0110u8 reversed is 0b0110_0000u8, and 0x0001u16 reversed is 0x8000u16.
+macro_rules! impl_reverse_bits {
+    ($($scalar:ty),*) => {
+        $(
+            impl Endian<$scalar> {
+                /// Reverses the order of the bits in the native value, so the least significant bit becomes the most significant.
+                /// This is not byte swapping; the bits within each byte are reversed as well.
+                /// The value is cast to native endianness first, so the result doesn't depend on the tag.
+                /// ```
+                /// use scalar_types::Endian;
+                /// fn main() {
+                #[doc = concat!("    let top_bit = 1", stringify!($scalar), " << (", stringify!($scalar), "::BITS - 1);")]
+                ///
+                #[doc = concat!("    assert_eq!(Endian::new(1", stringify!($scalar), ").reverse_bits(), top_bit);")]
+                ///     assert_eq!(Endian::new(top_bit).reverse_bits(), 1);
+                #[doc = concat!("    assert_eq!(Endian::new(0b0110", stringify!($scalar), ").reverse_bits(), 0b0110 << (", stringify!($scalar), "::BITS - 4));")]
+                /// }
+                /// ```
+                pub fn reverse_bits(&self) -> $scalar {
+                    self.unpack().reverse_bits()
+                }
+            }
+        )*
+    };
+}
+
+impl_reverse_bits!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// Wraps a plain value as Endian::Native(T), the same as Endian::new.
+/// ```
+/// use scalar_types::Endian;
+/// fn main() {
+///     let scalar_types: Endian<u16> = 42u16.into();
+///
+///     assert_eq!(scalar_types.is_native(), true);
+/// }
+/// ```
+impl<T: Copy + Default> From<T> for Endian<T> {
+    fn from(value: T) -> Endian<T> {
+        Endian::new(value)
+    }
+}
+
+/// Tags a value with the given endianness, the same as Endian::with_order.
+/// No byte manipulation occurs; the caller asserts the value is already stored in that endianness.
+/// Being a From impl, it also provides TryFrom for generic conversion code.
+/// ```
+/// use scalar_types::Endian;
+/// fn main() {
+///     let scalar_types: Endian<u16> = (0x1234u16, Endian::Little(())).into();
+///
+///     assert_eq!(scalar_types, Endian::Little(0x1234u16));
+/// }
+/// ```
+impl<T: Copy + Default> From<(T, Endian<()>)> for Endian<T> {
+    fn from((value, order): (T, Endian<()>)) -> Endian<T> {
+        Endian::with_order(value, order)
+    }
+}
+
+/// Generates an Endian<T> with a random variant and value, for fuzzing.
+/// Lets fuzz harnesses derive Arbitrary on structs holding Endian<T> fields.
+/// Only available with the "arbitrary" feature.
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use scalar_types::Endian;
+/// fn main() {
+///     // Two bytes for the value, then one byte picking the variant
+///     let data = [0x34u8, 0x12, 1];
+///     let mut u = Unstructured::new(&data);
+///
+///     assert_eq!(Endian::<u16>::arbitrary(&mut u).unwrap(), Endian::Big(0x1234));
+/// }
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Endian<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let value = T::arbitrary(u)?;
+
+        match u.int_in_range(0..=2u8)? {
+            0 => Ok(Endian::Little(value)),
+            1 => Ok(Endian::Big(value)),
+            _ => Ok(Endian::Native(value))
+        }
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(T::size_hint(depth), <u8 as arbitrary::Arbitrary>::size_hint(depth))
+    }
+}
+
+/// CastAll
+/// Casts every Endian<T> in a container to a specified endianness, keeping the container's shape.
+/// Implemented for Vec, slices, arrays, and HashMap values.
+///
+/// If any of the casts fail, None is returned instead.
+/// ```
+/// use scalar_types::{CastAll, Endian};
+/// use std::collections::HashMap;
+/// fn main() {
+///     let array = [Endian::Little(1u16), Endian::Big(2u16)];
+///     assert_eq!(array.cast_all(Endian::Little(())), Some([1u16, 512]));
+///
+///     let vector = vec![Endian::new(1u32), Endian::new(2u32)];
+///     assert_eq!(vector.cast_all(Endian::Native(())), Some(vec![1u32, 2]));
+///
+///     let mut map = HashMap::new();
+///     map.insert("offset", Endian::Big(0x0100u16));
+///     assert_eq!(map.cast_all(Endian::Big(())).unwrap()["offset"], 0x0100);
+/// }
+/// ```
+pub trait CastAll {
+    /// The same container, holding the cast values
+    type Output;
+
+    /// Casts every element to `order`.
+    fn cast_all(self, order: Endian<()>) -> Option<Self::Output>;
+}
+
+impl<T: Copy + Default> CastAll for Vec<Endian<T>> {
+    type Output = Vec<T>;
+
+    fn cast_all(self, order: Endian<()>) -> Option<Vec<T>> {
+        self.iter().map(|value| value.cast(order)).collect()
+    }
+}
+
+impl<T: Copy + Default> CastAll for &[Endian<T>] {
+    type Output = Vec<T>;
+
+    fn cast_all(self, order: Endian<()>) -> Option<Vec<T>> {
+        self.iter().map(|value| value.cast(order)).collect()
+    }
+}
+
+impl<T: Copy + Default, const N: usize> CastAll for [Endian<T>; N] {
+    type Output = [T; N];
+
+    fn cast_all(self, order: Endian<()>) -> Option<[T; N]> {
+        let mut values = [T::default(); N];
+        for (slot, value) in values.iter_mut().zip(self.iter()) {
+            *slot = value.cast(order)?;
+        }
+
+        Some(values)
+    }
+}
+
+impl<K, T, HasherT> CastAll for std::collections::HashMap<K, Endian<T>, HasherT>
+where K: Eq + std::hash::Hash, T: Copy + Default, HasherT: std::hash::BuildHasher + Default {
+    type Output = std::collections::HashMap<K, T, HasherT>;
+
+    fn cast_all(self, order: Endian<()>) -> Option<Self::Output> {
+        self.into_iter()
+            .map(|(key, value)| value.cast(order).map(|value| (key, value)))
+            .collect()
+    }
+}
+
+/// ReadPlan
+/// Describes reading a value from a stream, then validating and transforming it, in one expression.
+/// Saves nesting a chain of and_then calls around from_stream and cast.
+/// ```
+/// use scalar_types::{Endian, ReadPlan};
+/// fn main() {
+///     let data = [0u8, 0, 0, 21, 0, 0, 0, 0];
+///     let mut stream = &data[..];
+///
+///     let plan = ReadPlan::new(Endian::Big(()))
+///         .read::<u32>()
+///         .validate(|value| *value != 0)
+///         .map(|value| value * 2);
+///     assert_eq!(plan.run(&mut stream), Some(42));
+///
+///     // The second value is zero, so it fails validation
+///     let plan = ReadPlan::new(Endian::Big(()))
+///         .read::<u32>()
+///         .validate(|value| *value != 0);
+///     assert_eq!(plan.run(&mut stream), None);
+/// }
+/// ```
+pub struct ReadPlan {
+    order: Endian<()>
+}
+
+impl ReadPlan {
+    /// Creates a plan that casts the value it reads from `order` to native endianness.
+    pub fn new(order: Endian<()>) -> ReadPlan {
+        ReadPlan { order }
+    }
+
+    /// Chooses the type of value the plan reads.
+    pub fn read<T: Scalar>(self) -> ReadPipeline<T, fn(T) -> Option<T>> {
+        ReadPipeline {
+            order: self.order,
+            pipeline: Some,
+            marker: std::marker::PhantomData
+        }
+    }
+}
+
+/// ReadPipeline
+/// A ReadPlan with its value type chosen. Created by ReadPlan::read.
+/// Each validate or map call adds a step, which run applies in order after reading and casting.
+pub struct ReadPipeline<T, F> {
+    order: Endian<()>,
+    pipeline: F,
+    marker: std::marker::PhantomData<T>
+}
+
+impl<T: Scalar, U, F: FnOnce(T) -> Option<U>> ReadPipeline<T, F> {
+    /// Adds a check. If the predicate returns false, run returns None.
+    pub fn validate<P: FnOnce(&U) -> bool>(self, predicate: P) -> ReadPipeline<T, impl FnOnce(T) -> Option<U>> {
+        let pipeline = self.pipeline;
+        ReadPipeline {
+            order: self.order,
+            pipeline: move |value| pipeline(value).filter(predicate),
+            marker: std::marker::PhantomData
+        }
+    }
+
+    /// Adds a transform applied to the value.
+    pub fn map<V, M: FnOnce(U) -> V>(self, transform: M) -> ReadPipeline<T, impl FnOnce(T) -> Option<V>> {
+        let pipeline = self.pipeline;
+        ReadPipeline {
+            order: self.order,
+            pipeline: move |value| pipeline(value).map(transform),
+            marker: std::marker::PhantomData
+        }
+    }
+
+    /// Reads a T from the stream, casts it to native endianness, then applies each step.
+    /// Returns None if the read fails, or if a validation step rejects the value.
+    pub fn run<StreamT: std::io::Read>(self, stream: &mut StreamT) -> Option<U> {
+        let value = Endian::<T>::from_stream(stream)?.cast(self.order)?;
+        (self.pipeline)(value)
+    }
+}
+
+/// SizeCalculator
+/// Adds up the on-disk size of a sequence of scalar fields, without having to write them.
+/// Useful for reserving space, or building offset tables up front.
+///
+/// Every method is const, so record sizes can be computed at compile time.
+/// ```
+/// use scalar_types::SizeCalculator;
+/// fn main() {
+///     // struct Header { magic: u32, version: u16, flags: u16, length: u64 }
+///     const HEADER_SIZE: usize = SizeCalculator::new()
+///         .add::<u32>()
+///         .add::<u16>()
+///         .add::<u16>()
+///         .add::<u64>()
+///         .size();
+///
+///     assert_eq!(HEADER_SIZE, 16);
+/// }
+/// ```
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Clone, Copy)]
+pub struct SizeCalculator {
+    size: usize
+}
+
+impl SizeCalculator {
+    /// Creates an empty calculator, with a size of 0.
+    pub const fn new() -> SizeCalculator {
+        SizeCalculator { size: 0 }
+    }
+
+    /// Adds a field of type T.
+    pub const fn add<T: Scalar>(self) -> SizeCalculator {
+        SizeCalculator { size: self.size + T::SIZE }
+    }
+
+    /// Adds `count` consecutive fields of type T.
+    pub const fn add_array<T: Scalar>(self, count: usize) -> SizeCalculator {
+        SizeCalculator { size: self.size + T::SIZE * count }
+    }
+
+    /// Returns the total size of the fields added so far, in bytes.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Record
+/// Holds the raw bytes of a fixed size record, along with the record's endianness.
+/// Fields are decoded on demand by offset and type, so fields that are never accessed are never parsed.
+/// The record is read from the stream once, and fields can be decoded as many times as needed.
+/// ```
+/// use scalar_types::{Endian, Record};
+/// fn main() {
+///     // A big endian record: u32 id, u16 flags, u16 count
+///     let data = [0u8, 0, 0, 7, 0, 1, 0, 3];
+///
+///     let record = match Record::from_stream(&mut &data[..], 8, Endian::Big(())) {
+///         Some(record) => record,
+///         None => panic!("Unable to read record from stream!")
+///     };
+///
+///     assert_eq!(record.field::<u16>(6), Some(3));
+///     assert_eq!(record.field::<u32>(0), Some(7));
+///
+///     // Out of bounds
+///     assert_eq!(record.field::<u32>(6), None);
+/// }
+/// ```
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Record {
+    bytes: Vec<u8>,
+    order: Endian<()>
+}
+
+impl Record {
+    /// Wraps bytes that have already been read, tagging them with the record's endianness.
+    pub fn new(bytes: Vec<u8>, order: Endian<()>) -> Record {
+        Record { bytes, order }
+    }
+
+    /// Reads `size` bytes from any type that implements the std::io::Read trait, as a record in `order`.
+    /// Returns None on a short read.
+    pub fn from_stream<StreamT: std::io::Read>(stream: &mut StreamT, size: usize, order: Endian<()>) -> Option<Record> {
+        let mut bytes = vec![0u8; size];
+        stream.read_exact(&mut bytes).ok()?;
+
+        Some(Record::new(bytes, order))
+    }
+
+    /// Decodes the T starting at `offset` bytes into the record, cast from the record's endianness to native.
+    /// Returns None if the field would run past the end of the record.
+    pub fn field<T: Scalar>(&self, offset: usize) -> Option<T> {
+        let end = offset.checked_add(T::SIZE)?;
+        let mut bytes = self.bytes.get(offset..end)?;
+
+        Endian::<T>::from_stream(&mut bytes)?.cast(self.order)
+    }
+
+    /// Returns the endianness the record was tagged with.
+    pub fn order(&self) -> Endian<()> {
+        self.order
+    }
+
+    /// Returns the record's raw bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the size of the record in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns true if the record holds no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+/// Converter
+/// Caches the decision of whether values from a source endianness need swapping on this system.
+/// The decision is constant for a whole file, so making it once saves redoing it for every cast in bulk workloads.
+/// ```
+/// use scalar_types::{Converter, Endian};
+/// fn main() {
+///     let converter = Converter::new(Endian::Big(()));
+///
+///     let data = [0u8, 0, 0, 1, 0, 0, 0, 2];
+///     let values: Vec<u32> = Endian::<u32>::stream_iter_with_offset(&mut &data[..])
+///         .map(|(_, value)| converter.convert(value))
+///         .collect();
+///
+///     assert_eq!(values, vec![1, 2]);
+/// }
+/// ```
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+pub struct Converter {
+    source: Endian<()>,
+    should_swap: bool
+}
+
+impl Converter {
+    /// Creates a converter for values stored in the `source` endianness.
+    pub fn new(source: Endian<()>) -> Converter {
+        let host = native_endianness();
+        let should_swap = match source {
+            Endian::Little(()) => host.is_big(),
+            Endian::Big(()) => host.is_little(),
+            Endian::Native(()) => false
+        };
+
+        Converter { source, should_swap }
+    }
+
+    /// Returns the endianness the converter reads values from.
+    pub fn source(&self) -> Endian<()> {
+        self.source
+    }
+
+    /// Returns true if values from the source endianness are swapped on this system.
+    pub fn should_swap(&self) -> bool {
+        self.should_swap
+    }
+
+    /// Converts a value stored in the source endianness to native endianness.
+    ///
+    /// Values tagged Endian::Native are raw bytes, as read by from_stream, and are interpreted in the source endianness.
+    /// Values tagged with the source endianness use the cached decision as well.
+    /// Any other tag is honoured, falling back to a regular cast.
+    /// ```
+    /// use scalar_types::{Converter, Endian};
+    /// fn main() {
+    ///     let converter = Converter::new(Endian::Big(()));
+    ///
+    ///     assert_eq!(converter.convert(Endian::Native(u16::from_ne_bytes([1, 0]))), 256);
+    ///     assert_eq!(converter.convert(Endian::Big(u16::from_ne_bytes([1, 0]))), 256);
+    ///     assert_eq!(converter.convert(Endian::Little(u16::from_ne_bytes([1, 0]))), 1);
+    /// }
+    /// ```
+    pub fn convert<T: Copy + Default>(&self, value: Endian<T>) -> T {
+        let order = value.order();
+        if order != self.source && !order.is_native() {
+            return Converter::new(order).convert(value);
+        }
+
+        if self.should_swap {
+            endian_swap_unsafe(value.raw())
+        } else {
+            value.raw()
+        }
+    }
+}
+
+/// ChunkedReader
+/// Wraps any type that implements the std::io::Read trait, and presents it as one seamless byte stream.
+/// For chunked transports (like fixed size network frames), where a scalar might straddle two chunks.
+///
+/// It is a thin wrapper around std::io::BufReader. It doesn't read any differently; it exists to give
+/// chunked transports a named adapter whose reassembly across chunk boundaries is guaranteed and tested here.
+/// A read hands back whatever is buffered without waiting for more data, so it never blocks a live socket
+/// or pipe once bytes are available. read_exact (and so from_stream) keeps reading across chunk boundaries
+/// until a value is complete. It also implements std::io::BufRead, so line based readers like read_text_scalar work too.
+/// ```
+/// use scalar_types::{ChunkedReader, Endian};
+/// use std::io::{BufRead, Read};
+///
+/// // Hands out at most `frame` bytes per read, like a transport delivering fixed size frames
+/// struct Frames<'a> {
+///     data: &'a [u8],
+///     frame: usize
+/// }
+///
+/// impl Read for Frames<'_> {
+///     fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+///         let count = self.frame.min(buffer.len()).min(self.data.len());
+///         buffer[..count].copy_from_slice(&self.data[..count]);
+///         self.data = &self.data[count..];
+///         Ok(count)
+///     }
+/// }
+///
+/// fn main() {
+///     // Big endian u16, u32, and u64
+///     let data = [0x12u8, 0x34, 0, 0, 0x56, 0x78, 0, 0, 0, 0, 0, 0, 0x9A, 0xBC];
+///
+///     // Odd frame sizes put the boundaries in the middle of every value at some point
+///     for frame in 1..=5 {
+///         let mut reader = ChunkedReader::with_capacity(Frames { data: &data, frame }, 4);
+///
+///         let first = Endian::<u16>::from_stream(&mut reader).and_then(|value| value.cast(Endian::Big(())));
+///         let second = Endian::<u32>::from_stream(&mut reader).and_then(|value| value.cast(Endian::Big(())));
+///         let third = Endian::<u64>::from_stream(&mut reader).and_then(|value| value.cast(Endian::Big(())));
+///
+///         assert_eq!(first, Some(0x1234));
+///         assert_eq!(second, Some(0x5678));
+///         assert_eq!(third, Some(0x9ABC));
+///         assert_eq!(Endian::<u8>::from_stream(&mut reader), None);
+///     }
+///
+///     // A plain read returns what is available, read_exact reassembles the rest
+///     let mut reader = ChunkedReader::new(Frames { data: &data, frame: 3 });
+///     let mut buffer = [0u8; 8];
+///     assert_eq!(reader.read(&mut buffer).unwrap(), 3);
+///     reader.read_exact(&mut buffer[3..]).unwrap();
+///     assert_eq!(buffer, data[..8]);
+///
+///     // Buffered bytes can be peeked at through BufRead before they are consumed
+///     let mut reader = ChunkedReader::new(Frames { data: &data, frame: 3 });
+///     assert_eq!(reader.fill_buf().unwrap(), &data[..3]);
+///     reader.consume(2);
+///     assert_eq!(reader.fill_buf().unwrap(), &data[2..3]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ChunkedReader<ReaderT> {
+    inner: std::io::BufReader<ReaderT>
+}
+
+impl<ReaderT: std::io::Read> ChunkedReader<ReaderT> {
+    /// Wraps a reader, with an 8KiB internal buffer.
+    pub fn new(inner: ReaderT) -> ChunkedReader<ReaderT> {
+        ChunkedReader::with_capacity(inner, 8 * 1024)
+    }
+
+    /// Wraps a reader, with an internal buffer of `capacity` bytes (at least 1).
+    pub fn with_capacity(inner: ReaderT, capacity: usize) -> ChunkedReader<ReaderT> {
+        ChunkedReader {
+            inner: std::io::BufReader::with_capacity(capacity.max(1), inner)
+        }
+    }
+
+    /// Returns the wrapped reader. Any data still in the internal buffer is lost.
+    pub fn into_inner(self) -> ReaderT {
+        self.inner.into_inner()
+    }
+}
+
+impl<ReaderT: std::io::Read> std::io::Read for ChunkedReader<ReaderT> {
+    fn read(&mut self, output: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(output)
+    }
+}
+
+impl<ReaderT: std::io::Read> std::io::BufRead for ChunkedReader<ReaderT> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.inner.consume(amount)
+    }
+}
+
+/// LazyScalar
+/// Pairs an Endian<T> with a decode transform (like removing a bias, or applying a scale).
+/// Both the swap and the transform are deferred until the value is actually needed,
+/// so fields that are never read never pay for either.
+/// ```
+/// use scalar_types::{Endian, LazyScalar};
+/// fn main() {
+///     // A big endian field stored with a bias of 100
+///     let data = [0u8, 142];
+///     let raw = Endian::<u16>::from_stream(&mut &data[..]).unwrap();
+///
+///     let field = LazyScalar::new(raw, |value| value - 100);
+///
+///     assert_eq!(field.get(Endian::Big(())), Some(42));
+/// }
+/// ```
+pub struct LazyScalar<T, F> {
+    value: Endian<T>,
+    transform: F
+}
+
+impl<T: Copy + Default, F: Fn(T) -> T> LazyScalar<T, F> {
+    /// Wraps a value with the transform to apply when it is read.
+    pub fn new(value: Endian<T>, transform: F) -> LazyScalar<T, F> {
+        LazyScalar { value, transform }
+    }
+
+    /// Casts the value using `order`, then applies the transform.
+    /// The transform runs again on every call; nothing is cached.
+    /// Casts always succeed, so this always returns Some; the Option mirrors cast.
+    pub fn get(&self, order: Endian<()>) -> Option<T> {
+        self.value.cast(order).map(&self.transform)
+    }
+
+    /// Returns the untransformed value.
+    pub fn raw(&self) -> &Endian<T> {
+        &self.value
+    }
+}
+
+/// EndianParser
+/// Configures a reader for a parsing session: the default endianness, an optional byte budget, and an optional checksum hasher.
+/// Then reader wraps a stream with that configuration, so the adapters don't have to be composed by hand.
+///
+/// The defaults are native endianness, no budget, and no hashing; so EndianParser::new().reader(stream) just works.
+/// ```
+/// use scalar_types::{Endian, EndianParser};
+/// use std::hash::Hasher;
+/// use std::io::Read;
+///
+/// // A simple additive checksum
+/// #[derive(Default)]
+/// struct Sum(u64);
+///
+/// impl Hasher for Sum {
+///     fn finish(&self) -> u64 {
+///         self.0
+///     }
+///
+///     fn write(&mut self, bytes: &[u8]) {
+///         self.0 += bytes.iter().map(|byte| *byte as u64).sum::<u64>();
+///     }
+/// }
+///
+/// fn main() {
+///     let data = [0u8, 1, 0, 2, 0, 3];
+///
+///     let mut reader = EndianParser::new()
+///         .order(Endian::Big(()))
+///         .budget(4)
+///         .hasher(Sum::default())
+///         .reader(&data[..]);
+///
+///     assert_eq!(reader.read_value::<u16>(), Some(1));
+///     assert_eq!(reader.read_value::<u16>(), Some(2));
+///
+///     // The budget of 4 bytes is spent
+///     assert_eq!(reader.read_value::<u16>(), None);
+///     assert_eq!(reader.checksum(), Some(3));
+///
+///     // The reader is a std::io::Read as well, and the budget applies there too
+///     let mut buffer = [0u8; 2];
+///     assert_eq!(reader.read(&mut buffer).unwrap(), 0);
+/// }
+/// ```
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct EndianParser<HasherT = std::hash::DefaultHasher> {
+    order: Endian<()>,
+    budget: Option<u64>,
+    hasher: Option<HasherT>
+}
+
+impl EndianParser {
+    /// Creates a parser configuration with native endianness, no budget, and no hashing.
+    pub fn new() -> EndianParser {
+        EndianParser {
+            order: Endian::Native(()),
+            budget: None,
+            hasher: None
+        }
+    }
+}
+
+impl Default for EndianParser {
+    fn default() -> EndianParser {
+        EndianParser::new()
+    }
+}
+
+impl<HasherT: std::hash::Hasher> EndianParser<HasherT> {
+    /// Sets the endianness values are cast from.
+    pub fn order(mut self, order: Endian<()>) -> EndianParser<HasherT> {
+        self.order = order;
+        self
+    }
+
+    /// Limits the reader to `bytes` bytes. Reads past the budget fail as though the stream ended.
+    pub fn budget(mut self, bytes: u64) -> EndianParser<HasherT> {
+        self.budget = Some(bytes);
+        self
+    }
+
+    /// Feeds every byte the reader reads into `hasher`.
+    pub fn hasher<NewHasherT: std::hash::Hasher>(self, hasher: NewHasherT) -> EndianParser<NewHasherT> {
+        EndianParser {
+            order: self.order,
+            budget: self.budget,
+            hasher: Some(hasher)
+        }
+    }
+
+    /// Wraps a stream with this configuration.
+    pub fn reader<ReaderT: std::io::Read>(self, inner: ReaderT) -> EndianReader<ReaderT, HasherT> {
+        EndianReader {
+            inner,
+            order: self.order,
+            remaining: self.budget,
+            hasher: self.hasher
+        }
+    }
+}
+
+/// EndianReader
+/// A stream configured by EndianParser. Exposes typed reads, and implements std::io::Read itself,
+/// so it can be passed to any of the crate's stream helpers while still enforcing the budget and hashing.
+pub struct EndianReader<ReaderT, HasherT> {
+    inner: ReaderT,
+    order: Endian<()>,
+    remaining: Option<u64>,
+    hasher: Option<HasherT>
+}
+
+impl<ReaderT: std::io::Read, HasherT: std::hash::Hasher> EndianReader<ReaderT, HasherT> {
+    /// Reads a T, and casts it from the configured endianness to native.
+    /// Returns None on a short read (including running out of budget).
+    pub fn read_value<T: Scalar>(&mut self) -> Option<T> {
+        Endian::<T>::from_stream(self)?.cast(self.order)
+    }
+
+    /// Returns the configured endianness.
+    pub fn order(&self) -> Endian<()> {
+        self.order
+    }
+
+    /// Returns how many bytes are left in the budget, or None if there is no budget.
+    pub fn remaining(&self) -> Option<u64> {
+        self.remaining
+    }
+
+    /// Returns the hasher's result over every byte read so far, or None if there is no hasher.
+    pub fn checksum(&self) -> Option<u64> {
+        self.hasher.as_ref().map(|hasher| hasher.finish())
+    }
+
+    /// Returns the wrapped stream.
+    pub fn into_inner(self) -> ReaderT {
+        self.inner
+    }
+}
+
+impl<ReaderT: std::io::Read, HasherT: std::hash::Hasher> std::io::Read for EndianReader<ReaderT, HasherT> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let limit = match self.remaining {
+            Some(remaining) => buffer.len().min(usize::try_from(remaining).unwrap_or(usize::MAX)),
+            None => buffer.len()
+        };
+
+        let read = self.inner.read(&mut buffer[..limit])?;
+
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= read as u64;
+        }
+        if let Some(hasher) = self.hasher.as_mut() {
+            hasher.write(&buffer[..read]);
+        }
+
+        Ok(read)
+    }
+}
+
+/// FlaggedWriter
+/// Writes a file in the layout from the crate's examples: a single endianness flag byte up front,
+/// followed by values all written in that endianness. The flag is 0x00 for little endian, and 0x01 for big.
+/// ```
+/// use scalar_types::{Endian, FlaggedWriter};
+/// fn main() {
+///     let mut writer = match FlaggedWriter::new(Vec::new(), Endian::Big(())) {
+///         Ok(writer) => writer,
+///         Err(_) => panic!("Unable to write flag!")
+///     };
+///
+///     writer.write(2u32).unwrap();
+///     writer.write(3u16).unwrap();
+///
+///     assert_eq!(writer.values_written(), 2);
+///     assert_eq!(writer.bytes_written(), 7);
+///     assert_eq!(writer.into_inner(), vec![1, 0, 0, 0, 2, 0, 3]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FlaggedWriter<WriterT> {
+    inner: WriterT,
+    order: Endian<()>,
+    values_written: u64,
+    bytes_written: u64
+}
+
+impl<WriterT: std::io::Write> FlaggedWriter<WriterT> {
+    /// Writes the flag byte for `order`, and returns a writer for values in that endianness.
+    /// Endian::Native(()) is resolved to the system's endianness, since the flag has to name a concrete one.
+    pub fn new(mut inner: WriterT, order: Endian<()>) -> std::io::Result<FlaggedWriter<WriterT>> {
+        let order = match order {
+            Endian::Native(()) => native_endianness(),
+            order => order
+        };
+
+        let flag = if order.is_big() { 0x01u8 } else { 0x00u8 };
+        inner.write_all(&[flag])?;
+
+        Ok(FlaggedWriter {
+            inner,
+            order,
+            values_written: 0,
+            bytes_written: 1
+        })
+    }
+
+    /// Casts a native endian value to the writer's endianness, and writes it.
+    pub fn write<T: Scalar>(&mut self, value: T) -> std::io::Result<()> {
+        let cast = match Endian::new(value).cast(self.order) {
+            Some(cast) => cast,
+            None => return Err(std::io::Error::other("Unable to determine the native endianness"))
+        };
+
+        let bytes = bytes_of_unsafe(&cast);
+        self.inner.write_all(bytes)?;
+
+        self.values_written += 1;
+        self.bytes_written += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Returns the endianness values are written in (never Endian::Native).
+    pub fn order(&self) -> Endian<()> {
+        self.order
+    }
+
+    /// Returns how many values have been written, not counting the flag.
+    pub fn values_written(&self) -> u64 {
+        self.values_written
+    }
+
+    /// Returns how many bytes have been written, including the flag byte.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    /// Returns the wrapped writer.
+    pub fn into_inner(self) -> WriterT {
+        self.inner
+    }
 }